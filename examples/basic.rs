@@ -6,9 +6,37 @@
 //! The inspector commands are:
 //! - world_inspector
 //! - resource_inspector
+//! - insert_resource
+//! - remove_resource
+//! - save_resource_preset
+//! - load_resource_preset
+//! - snapshot_resource
+//! - diff_resource
+//! - save_resource
+//! - load_resource
+//! - notify_resource_change
 //! - asset_inspector
 //! - state_inspector
 //! - filter_query_inspector
+//! - remove_component
+//! - toggle_disabled
+//! - spawn_from_ron
+//! - reset_to_default
+//! - inspect_lifecycle
+//! - set_filter_query_field
+//! - filter_stats
+//! - filter_query_next_page
+//! - filter_query_previous_page
+//! - sort_filter_query
+//! - export_filter_query
+//! - inspect_query_data
+//! - inspect_archetypes
+//! - watch
+//! - unwatch
+//! - plot_field
+//! - inspector_undo
+//! - inspector_redo
+//! - break_when
 use bevy::prelude::*;
 use bevy_inspector_egui::{bevy_egui::EguiPlugin, prelude::*};
 use bevy_minibuffer::prelude::*;
@@ -23,7 +51,7 @@ enum AppState {
 }
 
 #[derive(Reflect, Resource, Default, InspectorOptions)]
-#[reflect(Resource, InspectorOptions)]
+#[reflect(Resource, Default, InspectorOptions)]
 struct Configuration {
     name: String,
     #[inspector(min = 0.0, max = 1.0)]
@@ -31,13 +59,25 @@ struct Configuration {
 }
 
 #[derive(Reflect, Resource, Default, InspectorOptions)]
-#[reflect(Resource, InspectorOptions)]
+#[reflect(Resource, Default, InspectorOptions)]
 struct Settings {
     name: String,
     #[inspector(min = 0.0, max = 1.0)]
     option: f32,
 }
 
+/// A resource without `Reflect`, shown by its `Debug` output.
+#[derive(Resource, Debug)]
+struct Session {
+    players: Vec<String>,
+}
+
+/// A non-send resource, also shown by its `Debug` output.
+#[derive(Debug)]
+struct AudioDevice {
+    name: std::rc::Rc<str>,
+}
+
 fn plugin(app: &mut App) {
     app.add_plugins(MinibufferPlugins)
         .add_plugins(EguiPlugin::default())
@@ -46,12 +86,24 @@ fn plugin(app: &mut App) {
             inspector::WorldActs::default(),
             inspector::ResourceActs::default()
                 .add::<Configuration>()
-                .add::<Settings>(),
+                .add::<Settings>()
+                .add_debug::<Session>()
+                .add_non_send_debug::<AudioDevice>(),
             inspector::StateActs::default().add::<AppState>(),
             inspector::AssetActs::default().add::<StandardMaterial>(),
             inspector::FilterQueryActs::default()
                 .add::<With<Transform>>()
-                .add::<With<Mesh3d>>(),
+                .add::<With<Mesh3d>>()
+                .add_with_disabled::<With<Mesh3d>>()
+                .add_changed::<Transform>(120),
+            inspector::QueryDataActs::default().add::<(&Transform, Option<&Name>)>(),
+            inspector::ArchetypeActs::default(),
+            inspector::WatchActs::default(),
+            inspector::PlotActs::default(),
+            inspector::HistoryActs::default(),
+            inspector::BreakpointActs::default(),
+            inspector::EntityActs::default(),
+            inspector::LifecycleActs::default().add::<Mesh3d>(),
         ))
         .add_systems(Startup, |mut minibuffer: Minibuffer| {
             minibuffer.message("Type ':inspect Tab' to see the other inspectors.");
@@ -78,6 +130,12 @@ fn main() {
         .register_type::<Configuration>()
         .init_resource::<Settings>()
         .register_type::<Settings>()
+        .insert_resource(Session {
+            players: vec!["ada".into(), "grace".into()],
+        })
+        .insert_non_send_resource(AudioDevice {
+            name: "default".into(),
+        })
         .add_systems(Startup, setup)
         .add_systems(
             OnEnter(AppState::A),
@@ -0,0 +1,234 @@
+use crate::read_only::entity_ui;
+use bevy_app::{App, Plugin};
+use bevy_ecs::{
+    archetype::ArchetypeId,
+    component::StorageType,
+    prelude::{IntoScheduleConfigs, Res, ResMut, Resource, With, World},
+    schedule::SystemCondition,
+};
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    bevy_inspector, egui, DefaultInspectorConfigPlugin,
+};
+use bevy_minibuffer::{prelude::*, prompt::PromptState};
+use bevy_state::prelude::in_state;
+use std::cmp::Reverse;
+
+/// ## Adds the 'inspect_archetypes' act
+///
+/// This act toggles the visibility of an archetype browser. It lists every
+/// non-empty archetype with its entity count and components, each component's
+/// storage, and an estimated memory footprint per component column. Selecting
+/// an archetype opens a window of its entities.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::ArchetypeActs::default(),
+///         ));
+/// }
+/// ```
+pub struct ArchetypeActs {
+    acts: Acts,
+}
+
+impl ActsPlugin for ArchetypeActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl Default for ArchetypeActs {
+    fn default() -> Self {
+        Self {
+            acts: Acts::new([Act::new(inspect_archetypes)]),
+        }
+    }
+}
+
+impl Plugin for ArchetypeActs {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        app.init_resource::<ArchetypeBrowser>().add_systems(
+            EguiPrimaryContextPass,
+            archetypes_ui.run_if(
+                in_state(PromptState::Visible)
+                    .and(|browser: Res<ArchetypeBrowser>| browser.visible),
+            ),
+        );
+        self.warn_on_unused_acts();
+    }
+}
+
+/// The archetype browser's state.
+#[derive(Resource, Default)]
+struct ArchetypeBrowser {
+    visible: bool,
+    selected: Option<ArchetypeId>,
+}
+
+fn inspect_archetypes(mut browser: ResMut<ArchetypeBrowser>, mut minibuffer: Minibuffer) {
+    browser.visible = !browser.visible;
+    minibuffer.clear();
+}
+
+/// A component column of an archetype.
+struct ColumnSummary {
+    name: String,
+    storage: StorageType,
+    bytes: usize,
+}
+
+/// A non-empty archetype.
+struct ArchetypeSummary {
+    id: ArchetypeId,
+    len: usize,
+    columns: Vec<ColumnSummary>,
+}
+
+fn summarize(world: &World) -> Vec<ArchetypeSummary> {
+    let components = world.components();
+    let mut summaries: Vec<ArchetypeSummary> = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| !archetype.is_empty())
+        .map(|archetype| {
+            let len = archetype.len() as usize;
+            let mut columns: Vec<ColumnSummary> = archetype
+                .components()
+                .iter()
+                .filter_map(|id| {
+                    let info = components.get_info(*id)?;
+                    Some(ColumnSummary {
+                        name: info.name().shortname().to_string(),
+                        storage: info.storage_type(),
+                        bytes: info.layout().size() * len,
+                    })
+                })
+                .collect();
+            columns.sort_by(|a, b| a.name.cmp(&b.name));
+            ArchetypeSummary {
+                id: archetype.id(),
+                len,
+                columns,
+            }
+        })
+        .collect();
+    summaries.sort_by_key(|summary| Reverse(summary.len));
+    summaries
+}
+
+fn archetypes_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let summaries = summarize(world);
+    let mut selected = world.resource::<ArchetypeBrowser>().selected;
+
+    egui::Window::new("Archetypes")
+        .default_size((320., 160.))
+        .show(egui_context.get_mut(), |ui| {
+            ui.label(format!(
+                "{} archetypes with {} entities",
+                summaries.len(),
+                summaries.iter().map(|summary| summary.len).sum::<usize>()
+            ));
+            egui::ScrollArea::both().show(ui, |ui| {
+                for summary in &summaries {
+                    let total: usize = summary.columns.iter().map(|column| column.bytes).sum();
+                    let header = format!(
+                        "#{} {} entities, {} components, ~{}",
+                        summary.id.index(),
+                        summary.len,
+                        summary.columns.len(),
+                        bytes(total)
+                    );
+                    egui::CollapsingHeader::new(header)
+                        .id_salt(summary.id)
+                        .show(ui, |ui| {
+                            if ui.button("Open entities").clicked() {
+                                selected = Some(summary.id);
+                            }
+                            egui::Grid::new(summary.id).striped(true).show(ui, |ui| {
+                                for column in &summary.columns {
+                                    ui.label(column.name.as_str());
+                                    ui.label(match column.storage {
+                                        StorageType::Table => "table",
+                                        StorageType::SparseSet => "sparse set",
+                                    });
+                                    ui.label(bytes(column.bytes));
+                                    ui.end_row();
+                                }
+                            });
+                        });
+                }
+                ui.allocate_space(ui.available_size());
+            });
+        });
+
+    if let Some(id) = selected {
+        let entities: Vec<_> = world
+            .archetypes()
+            .get(id)
+            .map(|archetype| {
+                archetype
+                    .entities()
+                    .iter()
+                    .map(|entity| entity.id())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut open = true;
+        egui::Window::new(format!("Archetype #{}", id.index()))
+            .open(&mut open)
+            .default_size((320., 160.))
+            .show(egui_context.get_mut(), |ui| {
+                ui.label(format!("{} entities", entities.len()));
+                egui::ScrollArea::both().show(ui, |ui| {
+                    for entity in &entities {
+                        egui::CollapsingHeader::new(bevy_inspector::guess_entity_name(
+                            world, *entity,
+                        ))
+                        .id_salt(entity)
+                        .show(ui, |ui| {
+                            entity_ui(world, *entity, ui);
+                        });
+                    }
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+        if !open {
+            selected = None;
+        }
+    }
+    world.resource_mut::<ArchetypeBrowser>().selected = selected;
+}
+
+/// Format a byte count.
+fn bytes(bytes: usize) -> String {
+    if bytes >= 1 << 20 {
+        format!("{:.1} MiB", bytes as f64 / (1 << 20) as f64)
+    } else if bytes >= 1 << 10 {
+        format!("{:.1} KiB", bytes as f64 / (1 << 10) as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
@@ -1,18 +1,27 @@
-use crate::{utils::pretty_type_name, InspectorPlugins, Inspectors};
-use bevy_app::{PluginGroup, PluginGroupBuilder};
-use bevy_asset::Asset;
+use crate::{
+    read_only::readonly_value_ui, utils::pretty_type_name, InspectorPlugins, Inspectors,
+    ReadOnlyInspectors,
+};
+use bevy_app::{App, PluginGroup, PluginGroupBuilder};
+use bevy_asset::{Asset, Assets};
 use bevy_ecs::{
-    prelude::{On, Res, ResMut},
+    prelude::{not, resource_exists, IntoScheduleConfigs, On, Res, ResMut, With, World},
     schedule::SystemCondition,
 };
-use bevy_inspector_egui::quick::AssetInspectorPlugin;
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    egui,
+    quick::AssetInspectorPlugin,
+    DefaultInspectorConfigPlugin,
+};
 use bevy_minibuffer::{prelude::*, prompt::PromptState};
 use bevy_reflect::Reflect;
 use bevy_state::prelude::in_state;
 
 /// ## Adds the 'inspect_asset' act
 ///
-/// This act toggles the visibility of added asset inspectors.
+/// This act toggles the visibility of added asset inspectors. Assets added
+/// with [AssetActs::add_read_only] are shown without allowing edits.
 ///
 /// ## Usage
 ///
@@ -49,22 +58,81 @@ impl AssetActs {
     /// Add an asset to be shown when prompted.
     pub fn add<A: Asset + Reflect>(mut self) -> Self {
         self.plugins
-            .add_inspector(pretty_type_name::<A>(), Self::asset_inspector_plugin::<A>);
+            .add_inspector(pretty_type_name::<A>(), |index, inspector_plugins| {
+                Self::asset_inspector_plugin::<A>(index, inspector_plugins, false)
+            });
+        self
+    }
+
+    /// Add an asset that is shown without allowing edits.
+    pub fn add_read_only<A: Asset + Reflect>(mut self) -> Self {
+        self.plugins
+            .add_inspector(pretty_type_name::<A>(), |index, inspector_plugins| {
+                Self::asset_inspector_plugin::<A>(index, inspector_plugins, true)
+            });
         self
     }
 
     fn asset_inspector_plugin<A: Asset + Reflect>(
         index: usize,
         inspector_plugins: &mut InspectorPlugins<Self>,
+        read_only: bool,
     ) {
-        inspector_plugins.add_plugin(
-            AssetInspectorPlugin::<A>::default().run_if(
-                in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
-            ),
-        );
+        if !read_only {
+            inspector_plugins.add_plugin(
+                AssetInspectorPlugin::<A>::default().run_if(
+                    in_state(PromptState::Visible)
+                        .and(InspectorPlugins::<Self>::visible(index))
+                        .and(not(resource_exists::<ReadOnlyInspectors>)),
+                ),
+            );
+        }
+        inspector_plugins.add_plugin(move |app: &mut App| {
+            if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+                app.add_plugins(DefaultInspectorConfigPlugin);
+            }
+            app.add_systems(
+                EguiPrimaryContextPass,
+                readonly_asset_ui::<A>.run_if(
+                    in_state(PromptState::Visible)
+                        .and(InspectorPlugins::<Self>::visible(index))
+                        .and(move |all: Option<Res<ReadOnlyInspectors>>| {
+                            read_only || all.is_some()
+                        }),
+                ),
+            );
+        });
     }
 }
 
+/// Show an asset's values without allowing edits.
+fn readonly_asset_ui<A: Asset + Reflect>(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let Some(assets) = world.get_resource::<Assets<A>>() else {
+        return;
+    };
+
+    egui::Window::new(pretty_type_name::<A>())
+        .default_size((320., 160.))
+        .show(egui_context.get_mut(), |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                for (id, asset) in assets.iter() {
+                    egui::CollapsingHeader::new(format!("{id}"))
+                        .id_salt(id)
+                        .show(ui, |ui| readonly_value_ui(world, asset, ui));
+                }
+                ui.allocate_space(ui.available_size());
+            });
+        });
+}
+
 impl Default for AssetActs {
     fn default() -> Self {
         Self {
@@ -0,0 +1,463 @@
+use crate::{
+    utils::{self, as_f64, entity_label, parse_field_value, registration, split_field_target},
+    EntityInspectors, Inspectors, RegisteredResources, ResourceActs,
+};
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::{
+    entity::{Entity, EntityHashSet},
+    name::Name,
+    prelude::{Commands, In, IntoScheduleConfigs, Mut, On, Res, Resource, World},
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
+use bevy_log::warn;
+use bevy_minibuffer::prelude::*;
+use bevy_reflect::{PartialReflect, ReflectPath, TypeRegistry};
+use bevy_time::{Time, Virtual};
+use std::{any::TypeId, cmp::Ordering};
+
+/// ## Adds the 'break_when' act
+///
+/// This act reads a condition on a field of a resource registered with
+/// [ResourceActs] or of a component like "Health.current <= 0". The operators
+/// are `<`, `<=`, `>`, `>=`, `==`, and `!=`, and the right-hand side is a RON
+/// value of the field's type. When the condition becomes true, virtual time is
+/// paused, the minibuffer is shown, and the resource's inspector or the
+/// entity's inspector from [EntityActs](crate::EntityActs) is opened.
+///
+/// Entering a condition that is already registered removes it. A condition
+/// that fails to evaluate is reported once and removed.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::BreakpointActs::default(),
+///         ));
+/// }
+/// ```
+pub struct BreakpointActs {
+    acts: Acts,
+}
+
+impl ActsPlugin for BreakpointActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl Default for BreakpointActs {
+    fn default() -> Self {
+        Self {
+            acts: Acts::new([Act::new(break_when)]),
+        }
+    }
+}
+
+impl Plugin for BreakpointActs {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Breakpoints>().add_systems(
+            Last,
+            check_breakpoints.run_if(|breakpoints: Res<Breakpoints>| !breakpoints.0.is_empty()),
+        );
+        self.warn_on_unused_acts();
+    }
+}
+
+/// A comparison operator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Op {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl Op {
+    /// Operators ordered so that two-character operators match first.
+    const ALL: [(&'static str, Op); 6] = [
+        ("<=", Op::Le),
+        (">=", Op::Ge),
+        ("==", Op::Eq),
+        ("!=", Op::Ne),
+        ("<", Op::Lt),
+        (">", Op::Gt),
+    ];
+
+    fn test(self, ordering: Ordering) -> bool {
+        match self {
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Ne => ordering != Ordering::Equal,
+        }
+    }
+}
+
+/// Split a condition like "Health.current <= 0" into its field target, its
+/// operator, and its right-hand side.
+pub(crate) fn parse_condition(condition: &str) -> Result<(&str, Op, &str), String> {
+    let (index, symbol, op) = Op::ALL
+        .iter()
+        .filter_map(|(symbol, op)| Some((condition.find(symbol)?, *symbol, *op)))
+        .min_by_key(|(index, _, _)| *index)
+        .ok_or("Expected a condition like \"Health.current <= 0\".")?;
+    Ok((
+        condition[..index].trim(),
+        op,
+        condition[index + symbol.len()..].trim(),
+    ))
+}
+
+/// Where a breakpoint's field lives.
+enum BreakSource {
+    /// A registered resource's index
+    Resource(usize),
+    /// Every entity with the component
+    Component(TypeId, ReflectComponent),
+}
+
+impl BreakSource {
+    /// Return the values to test: the resource's or each entity's component.
+    fn values<'w>(&self, world: &'w World) -> Vec<(Option<Entity>, &'w dyn PartialReflect)> {
+        match self {
+            BreakSource::Resource(index) => world
+                .get_resource::<RegisteredResources>()
+                .and_then(|resources| resources.0.get(*index))
+                .and_then(|resource| resource.reflect(world))
+                .map(|resource| (None, resource.as_partial_reflect()))
+                .into_iter()
+                .collect(),
+            BreakSource::Component(type_id, reflect_component) => {
+                let Some(component_id) = world.components().get_id(*type_id) else {
+                    return vec![];
+                };
+                world
+                    .archetypes()
+                    .iter()
+                    .filter(|archetype| archetype.contains(component_id))
+                    .flat_map(|archetype| archetype.entities())
+                    .filter_map(|entity| {
+                        let entity = entity.id();
+                        reflect_component
+                            .reflect(world.entity(entity))
+                            .map(|component| (Some(entity), component.as_partial_reflect()))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A condition's parsed right-hand side.
+enum Rhs {
+    Number(f64),
+    /// A RON value of the field's type, compared with `==` or `!=`
+    Value(Box<dyn PartialReflect>),
+}
+
+/// A condition to break on.
+struct Breakpoint {
+    /// The condition as entered like "Health.current <= 0"
+    condition: String,
+    source: BreakSource,
+    /// The reflect path like ".current"
+    path: String,
+    op: Op,
+    /// The right-hand side as entered
+    value: String,
+    /// The right-hand side, parsed once a value of the field is available
+    rhs: Option<Rhs>,
+    /// Whether the resource's condition was true last frame
+    hit: bool,
+    /// The entities whose condition was true last frame
+    hits: EntityHashSet,
+}
+
+impl Breakpoint {
+    /// Parse the right-hand side for the type of the field in `value`.
+    fn parse_rhs(
+        &self,
+        value: &dyn PartialReflect,
+        type_registry: &TypeRegistry,
+    ) -> Result<Rhs, String> {
+        let field = self
+            .path
+            .as_str()
+            .reflect_element(value)
+            .map_err(|e| e.to_string())?;
+        if as_f64(field).is_some() {
+            if let Ok(x) = self.value.parse::<f64>() {
+                return Ok(Rhs::Number(x));
+            }
+        }
+        if !matches!(self.op, Op::Eq | Op::Ne) {
+            return Err("Only `==` and `!=` compare values that are not numbers.".into());
+        }
+        parse_field_value(value, &self.path, &self.value, type_registry).map(Rhs::Value)
+    }
+
+    /// Return true if the field in `value` satisfies the condition.
+    fn test(&self, rhs: &Rhs, value: &dyn PartialReflect) -> Result<bool, String> {
+        let field = self
+            .path
+            .as_str()
+            .reflect_element(value)
+            .map_err(|e| e.to_string())?;
+        match rhs {
+            Rhs::Number(rhs) => {
+                let lhs = as_f64(field).ok_or_else(|| format!("{} is not a number.", self.path))?;
+                Ok(lhs.partial_cmp(rhs).is_some_and(|o| self.op.test(o)))
+            }
+            Rhs::Value(rhs) => {
+                let equal = field
+                    .reflect_partial_eq(rhs.as_ref())
+                    .ok_or_else(|| format!("Unable to compare {}.", self.condition))?;
+                // parse_rhs only accepts `==` and `!=` for values.
+                Ok(if self.op == Op::Eq { equal } else { !equal })
+            }
+        }
+    }
+
+    /// Test the condition on each current value and return what it became
+    /// true for this frame.
+    fn check(
+        &mut self,
+        world: &World,
+        type_registry: &TypeRegistry,
+    ) -> Result<Vec<BreakHit>, String> {
+        let values = self.source.values(world);
+        if self.rhs.is_none() {
+            let Some((_, value)) = values.first() else {
+                return Ok(vec![]);
+            };
+            self.rhs = Some(self.parse_rhs(*value, type_registry)?);
+        }
+        let rhs = self.rhs.as_ref().expect("rhs");
+        let mut new_hits = vec![];
+        let mut hit = false;
+        let mut hits = EntityHashSet::default();
+        for (entity, value) in values {
+            if !self.test(rhs, value)? {
+                continue;
+            }
+            match (entity, &self.source) {
+                (Some(entity), _) => {
+                    if !self.hits.contains(&entity) {
+                        new_hits.push(BreakHit::Entity(entity));
+                    }
+                    hits.insert(entity);
+                }
+                (None, BreakSource::Resource(index)) => {
+                    if !self.hit {
+                        new_hits.push(BreakHit::Resource(*index));
+                    }
+                    hit = true;
+                }
+                (None, BreakSource::Component(..)) => {}
+            }
+        }
+        self.hit = hit;
+        self.hits = hits;
+        Ok(new_hits)
+    }
+}
+
+/// The conditions to break on.
+#[derive(Resource, Default)]
+struct Breakpoints(Vec<Breakpoint>);
+
+fn break_when(mut minibuffer: Minibuffer) {
+    minibuffer.prompt::<TextField>("break when: ").observe(
+        |mut trigger: On<Submit<String>>, mut commands: Commands, mut minibuffer: Minibuffer| {
+            match trigger.event_mut().take_result() {
+                Ok(condition) => {
+                    commands.queue(move |world: &mut World| {
+                        let msg = match toggle_breakpoint(world, condition.trim()) {
+                            Ok(msg) => msg,
+                            Err(e) => e,
+                        };
+                        utils::message(world, msg);
+                    });
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+/// Add a breakpoint for a condition like "Health.current <= 0" or remove it
+/// if it was already added.
+fn toggle_breakpoint(world: &mut World, condition: &str) -> Result<String, String> {
+    let mut breakpoints = world.resource_mut::<Breakpoints>();
+    if let Some(index) = breakpoints
+        .0
+        .iter()
+        .position(|breakpoint| breakpoint.condition == condition)
+    {
+        breakpoints.0.remove(index);
+        return Ok(format!("Removed breakpoint {condition}."));
+    }
+    let (target, op, value) = parse_condition(condition)?;
+    let (type_path, path) = split_field_target(target);
+    let resource = world
+        .get_resource::<RegisteredResources>()
+        .and_then(|resources| {
+            resources
+                .0
+                .iter()
+                .position(|resource| resource.reflected() && resource.name() == type_path)
+        });
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let source = match resource {
+        Some(index) => BreakSource::Resource(index),
+        None => {
+            let registration = registration(&type_registry, type_path)
+                .ok_or_else(|| format!("No registered resource or component `{type_path}`."))?;
+            let reflect_component = registration
+                .data::<ReflectComponent>()
+                .cloned()
+                .ok_or_else(|| format!("`{type_path}` is not a component."))?;
+            BreakSource::Component(registration.type_id(), reflect_component)
+        }
+    };
+    let mut breakpoint = Breakpoint {
+        condition: condition.to_string(),
+        source,
+        path: path.to_string(),
+        op,
+        value: value.to_string(),
+        rhs: None,
+        hit: false,
+        hits: EntityHashSet::default(),
+    };
+    // Parse the right-hand side against a current value to report mistakes
+    // now. Without one, it is parsed when a value first appears.
+    if let Some((_, value)) = breakpoint.source.values(world).first() {
+        let rhs = breakpoint.parse_rhs(*value, &type_registry)?;
+        breakpoint.test(&rhs, *value)?;
+        breakpoint.rhs = Some(rhs);
+    }
+    world.resource_mut::<Breakpoints>().0.push(breakpoint);
+    Ok(format!("Break when {condition}."))
+}
+
+fn check_breakpoints(world: &mut World) {
+    let mut hits: Vec<(String, BreakHit)> = vec![];
+    let mut errors: Vec<String> = vec![];
+    world.resource_scope(|world, mut breakpoints: Mut<Breakpoints>| {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        breakpoints.0.retain_mut(|breakpoint| {
+            match breakpoint.check(world, &type_registry) {
+                Ok(new_hits) => {
+                    hits.extend(
+                        new_hits
+                            .into_iter()
+                            .map(|hit| (breakpoint.condition.clone(), hit)),
+                    );
+                    true
+                }
+                Err(e) => {
+                    // Report the error once and drop the breakpoint rather
+                    // than failing every frame.
+                    errors.push(format!("Removed breakpoint {}: {e}", breakpoint.condition));
+                    false
+                }
+            }
+        });
+    });
+    for e in &errors {
+        warn!("{e}");
+    }
+    if let Some(e) = errors.pop() {
+        utils::message(world, e);
+    }
+    let Some((condition, last)) = hits.last() else {
+        return;
+    };
+    let msg = match last {
+        BreakHit::Resource(_) => format!("Break: {condition}"),
+        BreakHit::Entity(entity) => format!(
+            "Break: {condition} on {}",
+            entity_label(*entity, world.get::<Name>(*entity))
+        ),
+    };
+    if let Some(mut time) = world.get_resource_mut::<Time<Virtual>>() {
+        time.pause();
+    }
+    for (_, hit) in hits {
+        match hit {
+            BreakHit::Resource(index) => {
+                if let Some(mut inspectors) = world.get_resource_mut::<Inspectors<ResourceActs>>() {
+                    inspectors.visible[index] = true;
+                }
+            }
+            BreakHit::Entity(entity) => {
+                if let Some(mut inspectors) = world.get_resource_mut::<EntityInspectors>() {
+                    if !inspectors.0.contains(&entity) {
+                        inspectors.0.push(entity);
+                    }
+                }
+            }
+        }
+    }
+    if let Err(e) = world.run_system_cached_with(
+        |In(msg): In<String>, mut minibuffer: Minibuffer| {
+            minibuffer.set_visible(true);
+            minibuffer.message(msg);
+        },
+        msg,
+    ) {
+        warn!("Unable to show breakpoint: {e}");
+    }
+}
+
+/// What a condition became true for.
+enum BreakHit {
+    /// A registered resource's index
+    Resource(usize),
+    Entity(Entity),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_conditions() {
+        assert_eq!(
+            parse_condition("Health.current <= 0"),
+            Ok(("Health.current", Op::Le, "0"))
+        );
+        assert_eq!(parse_condition("a.b>=-1"), Ok(("a.b", Op::Ge, "-1")));
+        assert_eq!(parse_condition("a.b < 1"), Ok(("a.b", Op::Lt, "1")));
+        assert_eq!(parse_condition("a.b > 1"), Ok(("a.b", Op::Gt, "1")));
+        assert_eq!(
+            parse_condition("State.mode == Paused"),
+            Ok(("State.mode", Op::Eq, "Paused"))
+        );
+        assert_eq!(parse_condition("a.b != 1"), Ok(("a.b", Op::Ne, "1")));
+        // The earliest operator splits the condition.
+        assert_eq!(
+            parse_condition("a.b < 1 <= 2"),
+            Ok(("a.b", Op::Lt, "1 <= 2"))
+        );
+        assert!(parse_condition("Health.current").is_err());
+    }
+}
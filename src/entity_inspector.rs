@@ -0,0 +1,539 @@
+use crate::{
+    read_only::{entity_ui, refuse_edit, READ_ONLY_MSG},
+    utils::{
+        clone_value, component_value, default_value, entity_label, entity_trie, message,
+        reflected_components,
+    },
+    EditHistory, EditTarget, ReadOnlyInspectors, RegisteredResources,
+};
+use bevy_app::{App, Plugin};
+use bevy_ecs::{
+    archetype::Archetypes,
+    component::Components,
+    entity::{Entities, Entity},
+    entity_disabling::Disabled,
+    name::Name,
+    prelude::{Commands, Has, In, IntoScheduleConfigs, On, Query, Res, Resource, With, World},
+    query::Allow,
+    reflect::{AppTypeRegistry, ReflectComponent},
+    world::EntityWorldMut,
+};
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    bevy_inspector, egui, DefaultInspectorConfigPlugin,
+};
+use bevy_log::warn;
+use bevy_minibuffer::{prelude::*, prompt::PromptState};
+use bevy_reflect::{
+    serde::TypedReflectDeserializer, std_traits::ReflectDefault, Reflect, ReflectFromReflect,
+    TypeRegistry,
+};
+use bevy_state::prelude::in_state;
+use serde::de::{self, DeserializeSeed, MapAccess, Visitor};
+use std::{any::TypeId, fmt};
+use trie_rs::map::Trie;
+
+/// ## Adds the 'remove_component', 'toggle_disabled', 'spawn_from_ron', and 'reset_to_default' acts
+///
+/// The 'remove_component' act prompts for an entity and then one of its
+/// reflected components to remove.
+///
+/// The 'toggle_disabled' act prompts for an entity and inserts or removes
+/// Bevy's [Disabled] marker, which hides it from queries without losing its
+/// state.
+///
+/// The 'spawn_from_ron' act reads a RON map of component type paths to values,
+/// spawns an entity with those components, and opens its inspector.
+///
+/// ```text
+/// {"Transform": (translation: (0.0, 1.0, 0.0)), "Name": "prop"}
+/// ```
+///
+/// The 'reset_to_default' act prompts for a resource registered with
+/// [ResourceActs](crate::ResourceActs) or an entity and one of its components,
+/// asks for confirmation, then applies the type's reflected default in place.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::EntityActs::default(),
+///         ));
+/// }
+/// ```
+pub struct EntityActs {
+    acts: Acts,
+}
+
+impl ActsPlugin for EntityActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl Default for EntityActs {
+    fn default() -> Self {
+        Self {
+            acts: Acts::new([
+                Act::new(remove_component),
+                Act::new(toggle_disabled),
+                Act::new(spawn_from_ron),
+                Act::new(reset_to_default),
+            ]),
+        }
+    }
+}
+
+impl Plugin for EntityActs {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+            app.add_plugins(DefaultInspectorConfigPlugin);
+        }
+        app.init_resource::<EntityInspectors>().add_systems(
+            EguiPrimaryContextPass,
+            entity_inspector_ui.run_if(in_state(PromptState::Visible)),
+        );
+        self.warn_on_unused_acts();
+    }
+}
+
+/// Entities with an open inspector window.
+#[derive(Resource, Default)]
+pub(crate) struct EntityInspectors(pub(crate) Vec<Entity>);
+
+fn entity_inspector_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    let entities = world.resource::<EntityInspectors>().0.clone();
+    let mut closed = vec![];
+    for entity in entities {
+        if world.get_entity(entity).is_err() {
+            closed.push(entity);
+            continue;
+        }
+        let mut open = true;
+        egui::Window::new(bevy_inspector::guess_entity_name(world, entity))
+            .id(egui::Id::new(("entity inspector", entity)))
+            .open(&mut open)
+            .default_size((320., 160.))
+            .show(egui_context.get_mut(), |ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    entity_ui(world, entity, ui);
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+        if !open {
+            closed.push(entity);
+        }
+    }
+    world
+        .resource_mut::<EntityInspectors>()
+        .0
+        .retain(|entity| !closed.contains(entity));
+}
+
+fn remove_component(
+    entities: Query<(Entity, Option<&Name>)>,
+    read_only: Option<Res<ReadOnlyInspectors>>,
+    mut minibuffer: Minibuffer,
+) {
+    if read_only.is_some() {
+        minibuffer.message(READ_ONLY_MSG);
+        return;
+    }
+    let Some(entities) = entity_trie(entities.iter()) else {
+        minibuffer.message("No entities.");
+        return;
+    };
+    minibuffer.prompt_map("entity: ", entities).observe(
+        |mut trigger: On<Completed<Entity>>,
+         mut minibuffer: Minibuffer,
+         entities: &Entities,
+         archetypes: &Archetypes,
+         components: &Components,
+         type_registry: Res<AppTypeRegistry>| {
+            let entity = match trigger.event_mut().state.take_result().unwrap() {
+                Ok(entity) => entity,
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                    return;
+                }
+            };
+            let names = reflected_components(
+                entity,
+                entities,
+                archetypes,
+                components,
+                &type_registry.read(),
+            );
+            if names.is_empty() {
+                minibuffer.message(format!("No reflected components on {entity}."));
+                return;
+            }
+            minibuffer
+                .prompt_map("component: ", Trie::from_iter(names))
+                .observe(
+                    move |mut trigger: On<Completed<TypeId>>,
+                          mut commands: Commands,
+                          mut minibuffer: Minibuffer,
+                          type_registry: Res<AppTypeRegistry>| {
+                        match trigger.event_mut().state.take_result().unwrap() {
+                            Ok(type_id) => {
+                                let type_registry = type_registry.read();
+                                let Some(registration) = type_registry.get(type_id) else {
+                                    return;
+                                };
+                                let Some(reflect_component) =
+                                    registration.data::<ReflectComponent>().cloned()
+                                else {
+                                    return;
+                                };
+                                commands
+                                    .entity(entity)
+                                    .queue(move |mut entity: EntityWorldMut| {
+                                        reflect_component.remove(&mut entity);
+                                    });
+                                minibuffer.message(format!(
+                                    "Removed {} from {entity}.",
+                                    registration.type_info().type_path_table().short_path()
+                                ));
+                            }
+                            Err(e) => {
+                                minibuffer.message(format!("{e}"));
+                            }
+                        }
+                    },
+                );
+        },
+    );
+}
+
+fn toggle_disabled(
+    entities: Query<(Entity, Option<&Name>), Allow<Disabled>>,
+    read_only: Option<Res<ReadOnlyInspectors>>,
+    mut minibuffer: Minibuffer,
+) {
+    if read_only.is_some() {
+        minibuffer.message(READ_ONLY_MSG);
+        return;
+    }
+    let Some(entities) = entity_trie(entities.iter()) else {
+        minibuffer.message("No entities.");
+        return;
+    };
+    minibuffer.prompt_map("entity: ", entities).observe(
+        |mut trigger: On<Completed<Entity>>,
+         mut commands: Commands,
+         mut minibuffer: Minibuffer,
+         disabled: Query<Has<Disabled>, Allow<Disabled>>| {
+            match trigger.event_mut().state.take_result().unwrap() {
+                Ok(entity) => {
+                    if disabled.get(entity).unwrap_or(false) {
+                        commands.entity(entity).remove::<Disabled>();
+                        minibuffer.message(format!("Enabled {entity}."));
+                    } else {
+                        commands.entity(entity).insert(Disabled);
+                        minibuffer.message(format!("Disabled {entity}."));
+                    }
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn spawn_from_ron(read_only: Option<Res<ReadOnlyInspectors>>, mut minibuffer: Minibuffer) {
+    if read_only.is_some() {
+        minibuffer.message(READ_ONLY_MSG);
+        return;
+    }
+    minibuffer.prompt::<TextField>("ron: ").observe(
+        |mut trigger: On<Submit<String>>,
+         mut commands: Commands,
+         mut minibuffer: Minibuffer,
+         type_registry: Res<AppTypeRegistry>| {
+            let input = match trigger.event_mut().take_result() {
+                Ok(input) => input,
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                    return;
+                }
+            };
+            let components = {
+                let type_registry = type_registry.read();
+                ron::Options::default().from_str_seed(&input, ComponentsSeed(&type_registry))
+            };
+            let components = match components {
+                Ok(components) => components,
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                    return;
+                }
+            };
+            let count = components.len();
+            let entity = commands.spawn_empty().id();
+            commands.queue(move |world: &mut World| {
+                let type_registry = world.resource::<AppTypeRegistry>().clone();
+                let type_registry = type_registry.read();
+                let mut entity_mut = world.entity_mut(entity);
+                for (reflect_component, value) in components {
+                    reflect_component.insert(
+                        &mut entity_mut,
+                        value.as_partial_reflect(),
+                        &type_registry,
+                    );
+                }
+                world.resource_mut::<EntityInspectors>().0.push(entity);
+            });
+            minibuffer.message(format!("Spawned {entity} with {count} components."));
+        },
+    );
+}
+
+/// What 'reset_to_default' prompts for first.
+#[derive(Clone, Copy, Debug)]
+enum ResetTarget {
+    /// A registered resource's index
+    Resource(usize),
+    Entity(Entity),
+}
+
+/// What 'reset_to_default' resets.
+#[derive(Clone, Copy, Debug)]
+enum Reset {
+    /// A registered resource's index
+    Resource(usize),
+    Component(Entity, TypeId),
+}
+
+fn reset_to_default(mut commands: Commands) {
+    commands.queue(|world: &mut World| {
+        if refuse_edit(world) {
+            return;
+        }
+        let mut targets: Vec<(String, ResetTarget)> = vec![];
+        if world.contains_resource::<RegisteredResources>() {
+            targets.extend(
+                RegisteredResources::names(world, |resource, present| {
+                    present && !resource.read_only()
+                })
+                .into_iter()
+                .map(|(name, index)| (name, ResetTarget::Resource(index))),
+            );
+        }
+        targets.extend(
+            world
+                .query::<(Entity, Option<&Name>)>()
+                .iter(world)
+                .map(|(entity, name)| (entity_label(entity, name), ResetTarget::Entity(entity))),
+        );
+        if let Err(e) = world.run_system_cached_with(prompt_reset_target, targets) {
+            warn!("Unable to prompt for reset: {e}");
+        }
+    });
+}
+
+fn prompt_reset_target(In(targets): In<Vec<(String, ResetTarget)>>, mut minibuffer: Minibuffer) {
+    if targets.is_empty() {
+        minibuffer.message("Nothing to reset.");
+        return;
+    }
+    minibuffer
+        .prompt_map("reset: ", Trie::from_iter(targets))
+        .observe(
+            |mut trigger: On<Completed<ResetTarget>>,
+             mut minibuffer: Minibuffer,
+             entities: &Entities,
+             archetypes: &Archetypes,
+             components: &Components,
+             type_registry: Res<AppTypeRegistry>,
+             resources: Option<Res<RegisteredResources>>| {
+                let entity = match trigger.event_mut().state.take_result().unwrap() {
+                    Ok(ResetTarget::Resource(index)) => {
+                        let name = resources
+                            .map(|resources| resources.0[index].name())
+                            .unwrap_or_default();
+                        confirm_reset(&mut minibuffer, name, Reset::Resource(index));
+                        return;
+                    }
+                    Ok(ResetTarget::Entity(entity)) => entity,
+                    Err(e) => {
+                        minibuffer.message(format!("{e}"));
+                        return;
+                    }
+                };
+                let names = reflected_components(
+                    entity,
+                    entities,
+                    archetypes,
+                    components,
+                    &type_registry.read(),
+                );
+                if names.is_empty() {
+                    minibuffer.message(format!("No reflected components on {entity}."));
+                    return;
+                }
+                minibuffer
+                    .prompt_map("component: ", Trie::from_iter(names.clone()))
+                    .observe(
+                        move |mut trigger: On<Completed<TypeId>>, mut minibuffer: Minibuffer| {
+                            match trigger.event_mut().state.take_result().unwrap() {
+                                Ok(type_id) => {
+                                    let name = names
+                                        .iter()
+                                        .find_map(|(name, id)| (*id == type_id).then_some(name))
+                                        .cloned()
+                                        .unwrap_or_default();
+                                    let label = format!("{name} on {entity}");
+                                    confirm_reset(
+                                        &mut minibuffer,
+                                        label,
+                                        Reset::Component(entity, type_id),
+                                    );
+                                }
+                                Err(e) => {
+                                    minibuffer.message(format!("{e}"));
+                                }
+                            }
+                        },
+                    );
+            },
+        );
+}
+
+fn confirm_reset(minibuffer: &mut Minibuffer, label: String, reset: Reset) {
+    minibuffer
+        .prompt_lookup(format!("Reset {label} to default? "), vec!["yes", "no"])
+        .observe(
+            move |mut trigger: On<Submit<String>>,
+                  mut commands: Commands,
+                  mut minibuffer: Minibuffer| {
+                match trigger.event_mut().take_result() {
+                    Ok(answer) if answer == "yes" => {
+                        commands.run_system_cached_with(reset_value, (label.clone(), reset));
+                    }
+                    Ok(_) => {
+                        minibuffer.message("Cancelled.");
+                    }
+                    Err(e) => {
+                        minibuffer.message(format!("{e}"));
+                    }
+                }
+            },
+        );
+}
+
+fn reset_value(In((label, reset)): In<(String, Reset)>, world: &mut World) {
+    let (target, type_id, before) = match reset {
+        Reset::Resource(index) => {
+            let resource = &world.resource::<RegisteredResources>().0[index];
+            (
+                EditTarget::Resource(index),
+                resource.resource_type_id(),
+                resource
+                    .reflect(world)
+                    .map(|value| clone_value(value.as_partial_reflect())),
+            )
+        }
+        Reset::Component(entity, type_id) => (
+            EditTarget::Component(entity, type_id),
+            type_id,
+            component_value(world, entity, type_id),
+        ),
+    };
+    let result = default_value(world, type_id).and_then(|value| {
+        target.write(world, value.as_partial_reflect())?;
+        Ok(value)
+    });
+    match result {
+        Ok(value) => {
+            if let Some(before) = before {
+                EditHistory::record(
+                    world,
+                    label.clone(),
+                    target,
+                    before,
+                    value.as_partial_reflect(),
+                );
+            }
+            message(world, format!("Reset {label} to default."));
+        }
+        Err(e) => message(world, e),
+    }
+}
+
+/// Deserializes a map of component type paths to values.
+///
+/// Each key may be a full or short type path of a registered component.
+struct ComponentsSeed<'a>(&'a TypeRegistry);
+
+impl<'de> DeserializeSeed<'de> for ComponentsSeed<'_> {
+    type Value = Vec<(ReflectComponent, Box<dyn Reflect>)>;
+
+    fn deserialize<D: de::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ComponentsSeed<'_> {
+    type Value = Vec<(ReflectComponent, Box<dyn Reflect>)>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of component type paths to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut components = vec![];
+        while let Some(type_path) = map.next_key::<String>()? {
+            let registration = self
+                .0
+                .get_with_type_path(&type_path)
+                .or_else(|| self.0.get_with_short_type_path(&type_path))
+                .ok_or_else(|| de::Error::custom(format!("No registered type `{type_path}`.")))?;
+            let reflect_component = registration
+                .data::<ReflectComponent>()
+                .cloned()
+                .ok_or_else(|| de::Error::custom(format!("`{type_path}` is not a component.")))?;
+            let value = map.next_value_seed(TypedReflectDeserializer::new(registration, self.0))?;
+            // Inserting a value that can't be completed would panic, so build
+            // the concrete component now.
+            let value = registration
+                .data::<ReflectFromReflect>()
+                .and_then(|from_reflect| from_reflect.from_reflect(value.as_ref()))
+                .or_else(|| {
+                    let mut component = registration.data::<ReflectDefault>()?.default();
+                    component.try_apply(value.as_ref()).ok()?;
+                    Some(component)
+                })
+                .ok_or_else(|| {
+                    de::Error::custom(format!(
+                        "Unable to build `{type_path}`; it needs reflected `FromReflect` or `Default`."
+                    ))
+                })?;
+            components.push((reflect_component, value));
+        }
+        Ok(components)
+    }
+}
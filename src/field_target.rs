@@ -0,0 +1,194 @@
+use crate::{
+    utils::{component_field_targets, entity_label, field_paths, registration, split_field_target},
+    RegisteredResources,
+};
+use bevy_ecs::{
+    entity::Entity,
+    name::Name,
+    prelude::{Commands, In, IntoSystem, On, Res, World},
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
+use bevy_log::warn;
+use bevy_minibuffer::prelude::*;
+use bevy_reflect::{PartialReflect, ReflectPath};
+use trie_rs::map::Trie;
+
+/// A reflected field of a registered resource or of an entity's component.
+#[derive(Clone)]
+pub(crate) struct FieldTarget {
+    /// A label like "Configuration.option" or "Transform.translation.y on
+    /// Player (3v0)"
+    pub(crate) label: String,
+    pub(crate) source: FieldSource,
+    /// The reflect path like ".translation.y" or empty for the whole value
+    pub(crate) path: String,
+}
+
+/// Where a [FieldTarget]'s value lives.
+#[derive(Clone)]
+pub(crate) enum FieldSource {
+    /// A registered resource's index
+    Resource(usize),
+    Component(Entity, ReflectComponent),
+}
+
+impl FieldTarget {
+    /// Return the field's current value.
+    pub(crate) fn read<'w>(&self, world: &'w World) -> Result<&'w dyn PartialReflect, String> {
+        let value = match &self.source {
+            FieldSource::Resource(index) => world
+                .get_resource::<RegisteredResources>()
+                .and_then(|resources| resources.0.get(*index))
+                .and_then(|resource| resource.reflect(world))
+                .map(|resource| resource.as_partial_reflect()),
+            FieldSource::Component(entity, reflect_component) => world
+                .get_entity(*entity)
+                .ok()
+                .and_then(|entity| reflect_component.reflect(entity))
+                .map(|component| component.as_partial_reflect()),
+        }
+        .ok_or("missing")?;
+        self.path
+            .as_str()
+            .reflect_element(value)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// What the first field prompt completes to.
+#[derive(Clone, Debug)]
+enum FieldChoice {
+    /// A registered resource's index and a field's path
+    Resource(usize, String),
+    Entity(Entity),
+}
+
+/// Prompt for a registered resource's field like "Configuration.option" or an
+/// entity and then one of its components' fields like
+/// "Transform.translation.y". Then run `next` with the chosen field.
+pub(crate) fn prompt_field_target<S, M>(commands: &mut Commands, prompt: &'static str, next: S)
+where
+    S: IntoSystem<In<FieldTarget>, (), M> + Copy + Send + Sync + 'static,
+    M: 'static,
+{
+    commands.queue(move |world: &mut World| {
+        let mut choices: Vec<(String, FieldChoice)> = vec![];
+        if let Some(resources) = world.get_resource::<RegisteredResources>() {
+            for (index, resource) in resources.0.iter().enumerate() {
+                let Some(value) = resource.reflect(world) else {
+                    continue;
+                };
+                let name = resource.name();
+                let paths =
+                    std::iter::once(String::new()).chain(field_paths(value.as_partial_reflect()));
+                choices.extend(
+                    paths.map(|path| (format!("{name}{path}"), FieldChoice::Resource(index, path))),
+                );
+            }
+        }
+        choices.extend(
+            world
+                .query::<(Entity, Option<&Name>)>()
+                .iter(world)
+                .map(|(entity, name)| (entity_label(entity, name), FieldChoice::Entity(entity))),
+        );
+        if let Err(e) = world.run_system_cached_with(
+            move |In((prompt, choices)): In<(&'static str, Vec<(String, FieldChoice)>)>,
+                  mut minibuffer: Minibuffer| {
+                if choices.is_empty() {
+                    minibuffer.message("No resources or entities.");
+                    return;
+                }
+                minibuffer
+                    .prompt_map(prompt, Trie::from_iter(choices))
+                    .observe(
+                        move |mut trigger: On<Completed<FieldChoice>>,
+                              mut commands: Commands,
+                              mut minibuffer: Minibuffer,
+                              resources: Option<Res<RegisteredResources>>| {
+                            match trigger.event_mut().state.take_result().unwrap() {
+                                Ok(FieldChoice::Resource(index, path)) => {
+                                    let name = resources
+                                        .as_ref()
+                                        .map(|resources| resources.0[index].name())
+                                        .unwrap_or_default();
+                                    let label = format!("{name}{path}");
+                                    commands.run_system_cached_with(
+                                        next,
+                                        FieldTarget {
+                                            label,
+                                            source: FieldSource::Resource(index),
+                                            path,
+                                        },
+                                    );
+                                }
+                                Ok(FieldChoice::Entity(entity)) => {
+                                    commands.queue(move |world: &mut World| {
+                                        prompt_component_field(world, entity, next);
+                                    });
+                                }
+                                Err(e) => {
+                                    minibuffer.message(format!("{e}"));
+                                }
+                            }
+                        },
+                    );
+            },
+            (prompt, choices),
+        ) {
+            warn!("Unable to prompt for field: {e}");
+        }
+    });
+}
+
+/// Prompt for a field of one of `entity`'s components, then run `next` with
+/// it.
+fn prompt_component_field<S, M>(world: &mut World, entity: Entity, next: S)
+where
+    S: IntoSystem<In<FieldTarget>, (), M> + Copy + Send + Sync + 'static,
+    M: 'static,
+{
+    let targets = component_field_targets(world, &[entity]);
+    let label = entity_label(entity, world.get::<Name>(entity));
+    if let Err(e) = world.run_system_cached_with(
+        move |In((entity, label, targets)): In<(Entity, String, Vec<String>)>,
+              mut minibuffer: Minibuffer| {
+            if targets.is_empty() {
+                minibuffer.message(format!("No reflected components on {label}."));
+                return;
+            }
+            minibuffer.prompt_lookup("field: ", targets).observe(
+                move |mut trigger: On<Submit<String>>,
+                      mut commands: Commands,
+                      mut minibuffer: Minibuffer,
+                      type_registry: Res<AppTypeRegistry>| {
+                    let target = match trigger.event_mut().take_result() {
+                        Ok(target) => target,
+                        Err(e) => {
+                            minibuffer.message(format!("{e}"));
+                            return;
+                        }
+                    };
+                    let (type_path, path) = split_field_target(&target);
+                    let reflect_component = registration(&type_registry.read(), type_path)
+                        .and_then(|registration| registration.data::<ReflectComponent>().cloned());
+                    let Some(reflect_component) = reflect_component else {
+                        minibuffer.message(format!("No reflected component `{type_path}`."));
+                        return;
+                    };
+                    commands.run_system_cached_with(
+                        next,
+                        FieldTarget {
+                            label: format!("{target} on {label}"),
+                            source: FieldSource::Component(entity, reflect_component),
+                            path: path.to_string(),
+                        },
+                    );
+                },
+            );
+        },
+        (entity, label, targets),
+    ) {
+        warn!("Unable to prompt for field: {e}");
+    }
+}
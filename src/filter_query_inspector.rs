@@ -1,17 +1,55 @@
-use crate::{utils::pretty_type_name, InspectorPlugins, Inspectors};
-use bevy_app::{PluginGroup, PluginGroupBuilder};
+use crate::{
+    read_only::{entity_ui, READ_ONLY_MSG},
+    utils::{self, component_field_targets, pretty_type_name, registration, split_field_target},
+    EditHistory, InspectorPlugins, Inspectors, ReadOnlyInspectors,
+};
+use bevy_app::{App, Last, PluginGroup, PluginGroupBuilder};
 use bevy_ecs::{
-    prelude::{On, Res, ResMut},
-    query::QueryFilter,
-    schedule::SystemCondition,
+    component::Component,
+    entity::{Entity, EntityHashMap},
+    entity_disabling::Disabled,
+    name::Name,
+    prelude::{
+        Commands, In, IntoScheduleConfigs, IntoSystem, Mut, On, Query, Res, ResMut, Resource, With,
+        World,
+    },
+    query::{Allow, Changed, QueryFilter, QueryState},
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    bevy_inspector, egui, DefaultInspectorConfigPlugin,
 };
-use bevy_inspector_egui::quick::FilterQueryInspectorPlugin;
+use bevy_log::warn;
 use bevy_minibuffer::{prelude::*, prompt::PromptState};
+use bevy_reflect::ReflectPath;
 use bevy_state::prelude::in_state;
+use std::{fmt, marker::PhantomData, sync::Mutex};
+use trie_rs::map::Trie;
 
-/// ## Adds the 'inspect_filter_query' act
+/// ## Adds the 'inspect_filter_query', 'set_filter_query_field', 'filter_stats', and paging acts
+///
+/// The 'inspect_filter_query' act toggles the visibility of the added filter
+/// query filters. Its completions show how many entities each filter currently
+/// matches. Each window shows its match count and one page of entities.
+///
+/// The 'filter_query_next_page' and 'filter_query_previous_page' acts page
+/// through every visible filter query window. The 'sort_filter_query' act
+/// orders a filter's entities by "query" order, "entity", "Name", or a field
+/// like "Transform.translation.y".
+///
+/// The 'set_filter_query_field' act prompts for a filter, a field like
+/// "PointLight.intensity", and a RON value, then sets that field on every
+/// entity the filter matches.
+///
+/// Filters added with [FilterQueryActs::add_changed] keep entities listed for
+/// a number of frames after they change.
 ///
-/// This act toggles the visibility of the added filter query filters.
+/// The 'export_filter_query' act prompts for a filter, comma separated fields,
+/// and a file path, then writes the matching entities to that CSV file.
+///
+/// The 'filter_stats' act prompts for a filter and a numeric field like
+/// "Transform.translation.y", then shows its count, min, max, and mean.
 ///
 /// ## Usage
 ///
@@ -32,9 +70,262 @@ use bevy_state::prelude::in_state;
 /// ```
 pub struct FilterQueryActs {
     plugins: InspectorPlugins<Self>,
+    queries: Vec<Box<dyn FilterQuery>>,
+    names: Vec<String>,
+    page_size: usize,
     acts: Acts,
 }
 
+/// Type-erased access to the entities a registered filter matches.
+pub(crate) trait FilterQuery: Send + Sync + 'static {
+    /// Return the entities that match the filter.
+    fn entities(&mut self, world: &mut World) -> Vec<Entity>;
+
+    /// Return how many entities match the filter.
+    fn count(&mut self, world: &mut World) -> usize {
+        self.entities(world).len()
+    }
+
+    /// Return a note to show beside a matching entity.
+    fn annotation(&self, _world: &World, _entity: Entity) -> Option<String> {
+        None
+    }
+}
+
+struct Filtered<F: QueryFilter>(Option<QueryState<Entity, F>>);
+
+impl<F: QueryFilter + 'static> FilterQuery for Filtered<F> {
+    fn entities(&mut self, world: &mut World) -> Vec<Entity> {
+        self.0
+            .get_or_insert_with(|| world.query_filtered::<Entity, F>())
+            .iter(world)
+            .collect()
+    }
+
+    fn count(&mut self, world: &mut World) -> usize {
+        self.0
+            .get_or_insert_with(|| world.query_filtered::<Entity, F>())
+            .iter(world)
+            .count()
+    }
+}
+
+/// Tracks when entities' `C` components last changed so `Changed<C>` matches
+/// linger for `decay` frames instead of a single frame.
+#[derive(Resource)]
+struct ChangeTracker<C: Component> {
+    frame: u32,
+    decay: u32,
+    changed: EntityHashMap<u32>,
+    marker: PhantomData<C>,
+}
+
+fn track_changes<C: Component>(
+    changed: Query<Entity, Changed<C>>,
+    mut tracker: ResMut<ChangeTracker<C>>,
+) {
+    tracker.frame = tracker.frame.wrapping_add(1);
+    let frame = tracker.frame;
+    for entity in &changed {
+        tracker.changed.insert(entity, frame);
+    }
+    let decay = tracker.decay;
+    tracker
+        .changed
+        .retain(|_, changed| frame.wrapping_sub(*changed) <= decay);
+}
+
+struct ChangeTracked<C: Component>(PhantomData<C>);
+
+impl<C: Component> FilterQuery for ChangeTracked<C> {
+    fn entities(&mut self, world: &mut World) -> Vec<Entity> {
+        let tracker = world.resource::<ChangeTracker<C>>();
+        let mut entities: Vec<Entity> = tracker
+            .changed
+            .keys()
+            .copied()
+            .filter(|entity| world.get_entity(*entity).is_ok())
+            .collect();
+        // Most recently changed first
+        entities.sort_by_key(|entity| std::cmp::Reverse(tracker.changed[entity]));
+        entities
+    }
+
+    fn annotation(&self, world: &World, entity: Entity) -> Option<String> {
+        let tracker = world.resource::<ChangeTracker<C>>();
+        let changed = tracker.changed.get(&entity)?;
+        Some(format!(
+            "changed {} frames ago",
+            tracker.frame.wrapping_sub(*changed)
+        ))
+    }
+}
+
+/// The registered filters' queries indexed like [Inspectors].
+#[derive(Resource)]
+pub(crate) struct FilterQueries(pub(crate) Vec<Box<dyn FilterQuery>>);
+
+impl FilterQueries {
+    /// Return the entities that match the filter at `index`.
+    pub(crate) fn entities(world: &mut World, index: usize) -> Vec<Entity> {
+        world.resource_scope(|world, mut queries: Mut<FilterQueries>| {
+            queries.0[index].entities(world)
+        })
+    }
+
+    /// Return the registered filters' names with their match counts.
+    pub(crate) fn counted_names(world: &mut World) -> CountedNames {
+        let names: Vec<String> = world
+            .resource::<FilterQueryViews>()
+            .views
+            .iter()
+            .map(|view| view.name.clone())
+            .collect();
+        world.resource_scope(|world, mut queries: Mut<FilterQueries>| {
+            let counts = (0..names.len())
+                .map(|index| queries.0[index].count(world))
+                .collect();
+            CountedNames {
+                names: Trie::from_iter(names.into_iter().enumerate().map(|(i, n)| (n, i))),
+                counts,
+            }
+        })
+    }
+}
+
+/// Completes filter names and labels each completion with its match count like
+/// "With<Mesh3d> (42)".
+#[derive(Clone)]
+pub(crate) struct CountedNames {
+    names: Trie<u8, usize>,
+    counts: Vec<usize>,
+}
+
+impl Lookup for CountedNames {
+    fn lookup(&self, input: &str) -> Result<(), LookupError> {
+        self.names.lookup(input)
+    }
+
+    fn longest_prefix(&self, input: &str) -> Option<String> {
+        Lookup::longest_prefix(&self.names, input)
+    }
+
+    fn all_lookups(&self, input: &str) -> Vec<String> {
+        self.names
+            .predictive_search::<String, _>(input)
+            .map(|(name, index)| format!("{name} ({})", self.counts[*index]))
+            .collect()
+    }
+}
+
+impl LookupMap for CountedNames {
+    type Item = usize;
+
+    fn resolve(&self, input: &str) -> Option<usize> {
+        self.names.resolve(input)
+    }
+}
+
+/// How a filter query window orders its entities.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) enum SortBy {
+    /// The order the filter returns
+    Query,
+    #[default]
+    Entity,
+    Name,
+    /// A field target like "Transform.translation.y"
+    Field(String),
+}
+
+impl SortBy {
+    /// Parse a sort choice like "entity", "Name", or a field target.
+    pub(crate) fn parse(input: &str) -> Self {
+        match input {
+            "query" => SortBy::Query,
+            "entity" => SortBy::Entity,
+            "Name" => SortBy::Name,
+            target => SortBy::Field(target.to_string()),
+        }
+    }
+
+    /// Sort the entities in place.
+    pub(crate) fn sort(&self, world: &World, entities: &mut [Entity]) {
+        match self {
+            SortBy::Query => {}
+            SortBy::Entity => entities.sort(),
+            SortBy::Name => entities.sort_by_cached_key(|entity| {
+                world
+                    .get::<Name>(*entity)
+                    .map(|name| name.as_str().to_owned())
+            }),
+            SortBy::Field(target) => {
+                let type_registry = world.resource::<AppTypeRegistry>().read();
+                let (type_path, path) = split_field_target(target);
+                let Some(reflect_component) = registration(&type_registry, type_path)
+                    .and_then(|registration| registration.data::<ReflectComponent>())
+                else {
+                    return;
+                };
+                entities.sort_by_cached_key(|entity| {
+                    let component = reflect_component.reflect(world.entity(*entity))?;
+                    path.reflect_element(component.as_partial_reflect())
+                        .ok()
+                        .map(utils::sort_key)
+                });
+            }
+        }
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortBy::Query => write!(f, "query"),
+            SortBy::Entity => write!(f, "entity"),
+            SortBy::Name => write!(f, "Name"),
+            SortBy::Field(target) => write!(f, "{target}"),
+        }
+    }
+}
+
+/// A filter query window's page and order.
+#[derive(Default)]
+pub(crate) struct FilterQueryView {
+    pub(crate) name: String,
+    page: usize,
+    sort: SortBy,
+    /// The matches last sorted and their sorted order
+    sorted: Option<(Vec<Entity>, Vec<Entity>)>,
+}
+
+impl FilterQueryView {
+    /// Return the matches in sorted order, only sorting again when the sort or
+    /// the matches changed.
+    fn sorted(world: &mut World, index: usize, matches: Vec<Entity>) -> Vec<Entity> {
+        let mut views = world.resource_mut::<FilterQueryViews>();
+        let view = &mut views.views[index];
+        if let Some((last, sorted)) = &view.sorted {
+            if *last == matches {
+                return sorted.clone();
+            }
+        }
+        let sort = view.sort.clone();
+        let mut sorted = matches.clone();
+        sort.sort(world, &mut sorted);
+        world.resource_mut::<FilterQueryViews>().views[index].sorted =
+            Some((matches, sorted.clone()));
+        sorted
+    }
+}
+
+/// The registered filters' views indexed like [Inspectors].
+#[derive(Resource)]
+pub(crate) struct FilterQueryViews {
+    page_size: usize,
+    pub(crate) views: Vec<FilterQueryView>,
+}
+
 impl ActsPluginGroup for FilterQueryActs {
     fn acts(&self) -> &Acts {
         &self.acts
@@ -48,22 +339,52 @@ impl ActsPluginGroup for FilterQueryActs {
 impl FilterQueryActs {
     /// Add a query filter.
     pub fn add<A: QueryFilter + 'static>(mut self) -> Self {
-        self.plugins.add_inspector(
-            pretty_type_name::<A>(),
-            Self::filter_query_inspector_plugin::<A>,
-        );
+        let name = pretty_type_name::<A>();
+        self.names.push(name.clone());
+        self.plugins.add_inspector(name, |_, _| {});
+        self.queries.push(Box::new(Filtered::<A>(None)));
         self
     }
 
-    fn filter_query_inspector_plugin<A: QueryFilter + 'static>(
-        index: usize,
-        inspector_plugins: &mut InspectorPlugins<Self>,
-    ) {
-        inspector_plugins.add_plugin(
-            FilterQueryInspectorPlugin::<A>::default().run_if(
-                in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
-            ),
-        );
+    /// Add a `Changed<C>` filter that keeps entities listed for `decay` frames
+    /// after their last change and shows how many frames ago they changed.
+    ///
+    /// A plain `Changed<C>` filter only matches entities for the frame they
+    /// changed, so its list flickers. Sort the filter by "query" order to list
+    /// the most recently changed first. Adding the same component twice is
+    /// ignored with a warning.
+    pub fn add_changed<C: Component>(mut self, decay: u32) -> Self {
+        let name = pretty_type_name::<Changed<C>>();
+        if self.names.contains(&name) {
+            warn!("{name} was already added; ignoring.");
+            return self;
+        }
+        self.names.push(name.clone());
+        self.plugins.add_inspector(name, move |_, plugins| {
+            plugins.add_plugin(move |app: &mut App| {
+                app.insert_resource(ChangeTracker::<C> {
+                    frame: 0,
+                    decay,
+                    changed: EntityHashMap::default(),
+                    marker: PhantomData,
+                })
+                .add_systems(Last, track_changes::<C>);
+            });
+        });
+        self.queries.push(Box::new(ChangeTracked::<C>(PhantomData)));
+        self
+    }
+
+    /// Add a query filter that also matches entities with the [Disabled]
+    /// marker, which Bevy's default query filters otherwise hide.
+    pub fn add_with_disabled<A: QueryFilter + 'static>(self) -> Self {
+        self.add::<(A, Allow<Disabled>)>()
+    }
+
+    /// Set the number of entities shown per page. The default is 50.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
     }
 }
 
@@ -71,23 +392,233 @@ impl Default for FilterQueryActs {
     fn default() -> Self {
         Self {
             plugins: InspectorPlugins::default(),
-            acts: Acts::new([Act::new(inspect_filter_query)]),
+            queries: vec![],
+            names: vec![],
+            page_size: 50,
+            acts: Acts::new([
+                Act::new(inspect_filter_query),
+                Act::new(set_filter_query_field),
+                Act::new(filter_stats),
+                Act::new(filter_query_next_page),
+                Act::new(filter_query_previous_page),
+                Act::new(sort_filter_query),
+                Act::new(export_filter_query),
+            ]),
         }
     }
 }
 
-fn inspect_filter_query(filters: Res<Inspectors<FilterQueryActs>>, mut minibuffer: Minibuffer) {
-    if !filters.visible.is_empty() {
-        minibuffer
-            .prompt_map("filter query: ", filters.names.clone())
-            .observe(
-                |mut trigger: On<Completed<usize>>,
-                 mut minibuffer: Minibuffer,
-                 mut filters: ResMut<Inspectors<FilterQueryActs>>| {
-                    match trigger.event_mut().state.take_result().unwrap() {
-                        Ok(index) => {
-                            filters.visible[index] = !filters.visible[index];
-                            minibuffer.clear();
+fn inspect_filter_query(
+    filters: Res<Inspectors<FilterQueryActs>>,
+    mut commands: Commands,
+    mut minibuffer: Minibuffer,
+) {
+    if filters.visible.is_empty() {
+        minibuffer.message("No filter queries registered.");
+        return;
+    }
+    commands.queue(|world: &mut World| {
+        let names = FilterQueries::counted_names(world);
+        if let Err(e) = world.run_system_cached_with(prompt_filter_query, names) {
+            warn!("Unable to prompt for filter query: {e}");
+        }
+    });
+}
+
+fn prompt_filter_query(In(names): In<CountedNames>, mut minibuffer: Minibuffer) {
+    minibuffer.prompt_map("filter query: ", names).observe(
+        |mut trigger: On<Completed<usize>>,
+         mut minibuffer: Minibuffer,
+         mut filters: ResMut<Inspectors<FilterQueryActs>>| {
+            match trigger.event_mut().state.take_result().unwrap() {
+                Ok(index) => {
+                    filters.visible[index] = !filters.visible[index];
+                    minibuffer.clear();
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn set_filter_query_field(
+    filters: Res<Inspectors<FilterQueryActs>>,
+    read_only: Option<Res<ReadOnlyInspectors>>,
+    mut minibuffer: Minibuffer,
+) {
+    if read_only.is_some() {
+        minibuffer.message(READ_ONLY_MSG);
+        return;
+    }
+    prompt_filter_fields(&filters, minibuffer, prompt_field_and_value);
+}
+
+fn filter_stats(filters: Res<Inspectors<FilterQueryActs>>, minibuffer: Minibuffer) {
+    prompt_filter_fields(&filters, minibuffer, prompt_stats_field);
+}
+
+/// Prompt for a registered filter, then run `next` with its index, the
+/// entities it matches, and the field targets of their components.
+fn prompt_filter_fields<S, M>(
+    filters: &Inspectors<FilterQueryActs>,
+    mut minibuffer: Minibuffer,
+    next: S,
+) where
+    S: IntoSystem<In<(usize, Vec<Entity>, Vec<String>)>, (), M> + Copy + Send + Sync + 'static,
+{
+    if filters.visible.is_empty() {
+        minibuffer.message("No filter queries registered.");
+        return;
+    }
+    minibuffer
+        .prompt_map("filter query: ", filters.names.clone())
+        .observe(
+            move |mut trigger: On<Completed<usize>>,
+                  mut commands: Commands,
+                  mut minibuffer: Minibuffer| {
+                match trigger.event_mut().state.take_result().unwrap() {
+                    Ok(index) => {
+                        commands.queue(move |world: &mut World| {
+                            let entities = FilterQueries::entities(world, index);
+                            let targets = component_field_targets(world, &entities);
+                            if let Err(e) =
+                                world.run_system_cached_with(next, (index, entities, targets))
+                            {
+                                warn!("Unable to prompt for field: {e}");
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        minibuffer.message(format!("{e}"));
+                    }
+                }
+            },
+        );
+}
+
+fn prompt_stats_field(
+    In((index, entities, targets)): In<(usize, Vec<Entity>, Vec<String>)>,
+    mut minibuffer: Minibuffer,
+) {
+    if entities.is_empty() {
+        minibuffer.message("No entities match.");
+        return;
+    }
+    minibuffer.prompt_lookup("field: ", targets).observe(
+        move |mut trigger: On<Submit<String>>,
+              mut commands: Commands,
+              mut minibuffer: Minibuffer| {
+            match trigger.event_mut().take_result() {
+                Ok(target) => {
+                    commands.queue(move |world: &mut World| {
+                        // Entities may have changed while prompting.
+                        let entities = FilterQueries::entities(world, index);
+                        let msg = match field_stats(world, &entities, &target) {
+                            Ok(Some(stats)) => format!("{target}: {stats}"),
+                            Ok(None) => format!("No numeric values for {target}."),
+                            Err(e) => e,
+                        };
+                        utils::message(world, msg);
+                    });
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+/// Summary statistics of a numeric field.
+struct Stats {
+    count: usize,
+    min: f64,
+    max: f64,
+    sum: f64,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "count {}, min {}, max {}, mean {}",
+            self.count,
+            self.min,
+            self.max,
+            self.sum / self.count as f64
+        )
+    }
+}
+
+/// Compute the statistics of a numeric field target like
+/// "Transform.translation.y" across the entities that have it.
+fn field_stats(world: &World, entities: &[Entity], target: &str) -> Result<Option<Stats>, String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let (type_path, path) = split_field_target(target);
+    let reflect_component = registration(&type_registry, type_path)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .ok_or_else(|| format!("No reflected component {type_path}."))?;
+    let mut stats: Option<Stats> = None;
+    for entity in entities {
+        let Some(component) = reflect_component.reflect(world.entity(*entity)) else {
+            continue;
+        };
+        let Some(x) = path
+            .reflect_element(component.as_partial_reflect())
+            .ok()
+            .and_then(utils::as_f64)
+        else {
+            continue;
+        };
+        let stats = stats.get_or_insert(Stats {
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            sum: 0.0,
+        });
+        stats.count += 1;
+        stats.min = stats.min.min(x);
+        stats.max = stats.max.max(x);
+        stats.sum += x;
+    }
+    Ok(stats)
+}
+
+fn prompt_field_and_value(
+    In((index, entities, targets)): In<(usize, Vec<Entity>, Vec<String>)>,
+    mut minibuffer: Minibuffer,
+) {
+    if entities.is_empty() {
+        minibuffer.message("No entities match.");
+        return;
+    }
+    minibuffer.prompt_lookup("field: ", targets).observe(
+        move |mut trigger: On<Submit<String>>, mut minibuffer: Minibuffer| {
+            let target = match trigger.event_mut().take_result() {
+                Ok(target) => target,
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                    return;
+                }
+            };
+            minibuffer.prompt::<TextField>("value: ").observe(
+                move |mut trigger: On<Submit<String>>,
+                      mut commands: Commands,
+                      mut minibuffer: Minibuffer| {
+                    match trigger.event_mut().take_result() {
+                        Ok(input) => {
+                            let target = target.clone();
+                            commands.queue(move |world: &mut World| {
+                                // Entities may have changed while prompting.
+                                let entities = FilterQueries::entities(world, index);
+                                let msg = match set_field(world, &entities, &target, &input) {
+                                    Ok(count) => format!("Set {target} on {count} entities."),
+                                    Err(e) => e,
+                                };
+                                utils::message(world, msg);
+                            });
                         }
                         Err(e) => {
                             minibuffer.message(format!("{e}"));
@@ -95,8 +626,329 @@ fn inspect_filter_query(filters: Res<Inspectors<FilterQueryActs>>, mut minibuffe
                     }
                 },
             );
+        },
+    );
+}
+
+/// Set a field target like "PointLight.intensity" to a RON value on each
+/// entity that has that component. Return the number of entities changed.
+fn set_field(
+    world: &mut World,
+    entities: &[Entity],
+    target: &str,
+    input: &str,
+) -> Result<usize, String> {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let (type_path, path) = split_field_target(target);
+    let reflect_component = registration(&type_registry, type_path)
+        .and_then(|registration| registration.data::<ReflectComponent>())
+        .ok_or_else(|| format!("No reflected component {type_path}."))?;
+    let Some(first) = entities
+        .iter()
+        .find_map(|entity| reflect_component.reflect(world.entity(*entity)))
+    else {
+        return Ok(0);
+    };
+    let value = utils::parse_field_value(first.as_partial_reflect(), path, input, &type_registry)?;
+    let mut count = 0;
+    for entity in entities {
+        let Some(mut component) = reflect_component.reflect_mut(world.entity_mut(*entity)) else {
+            continue;
+        };
+        if let Ok(field) = path.reflect_element_mut(component.as_partial_reflect_mut()) {
+            if field.try_apply(value.as_ref()).is_ok() {
+                count += 1;
+            }
+        }
+    }
+    Ok(count)
+}
+
+fn export_filter_query(filters: Res<Inspectors<FilterQueryActs>>, minibuffer: Minibuffer) {
+    prompt_filter_fields(&filters, minibuffer, prompt_export);
+}
+
+fn prompt_export(
+    In((index, _entities, targets)): In<(usize, Vec<Entity>, Vec<String>)>,
+    mut minibuffer: Minibuffer,
+) {
+    minibuffer
+        .prompt_lookup("fields (comma separated): ", FieldList(targets))
+        .observe(
+            move |mut trigger: On<Submit<String>>, mut minibuffer: Minibuffer| {
+                let fields: Vec<String> = match trigger.event_mut().take_result() {
+                    Ok(input) => input
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|field| !field.is_empty())
+                        .map(str::to_string)
+                        .collect(),
+                    Err(e) => {
+                        minibuffer.message(format!("{e}"));
+                        return;
+                    }
+                };
+                minibuffer.prompt::<TextField>("csv file: ").observe(
+                    move |mut trigger: On<Submit<String>>,
+                          mut commands: Commands,
+                          mut minibuffer: Minibuffer| {
+                        match trigger.event_mut().take_result() {
+                            Ok(path) => {
+                                let fields = fields.clone();
+                                commands.queue(move |world: &mut World| {
+                                    // Entities may have changed while prompting.
+                                    let entities = FilterQueries::entities(world, index);
+                                    let csv = filter_query_csv(world, &entities, &fields);
+                                    let msg = match std::fs::write(&path, csv) {
+                                        Ok(()) => {
+                                            format!("Wrote {} entities to {path}.", entities.len())
+                                        }
+                                        Err(e) => format!("Unable to write {path}: {e}"),
+                                    };
+                                    utils::message(world, msg);
+                                });
+                            }
+                            Err(e) => {
+                                minibuffer.message(format!("{e}"));
+                            }
+                        }
+                    },
+                );
+            },
+        );
+}
+
+/// Completes the last of a comma separated list of field targets.
+struct FieldList(Vec<String>);
+
+impl FieldList {
+    /// Split the input into the fields before the last one and the last one.
+    fn split(input: &str) -> (&str, &str) {
+        let head = input.rfind(',').map_or(0, |index| index + 1);
+        let head = head + input[head..].len() - input[head..].trim_start().len();
+        input.split_at(head)
+    }
+
+    fn matches<'a>(&'a self, last: &'a str) -> impl Iterator<Item = &'a String> {
+        self.0.iter().filter(move |target| target.starts_with(last))
+    }
+}
+
+impl Lookup for FieldList {
+    fn lookup(&self, input: &str) -> Result<(), LookupError> {
+        let (head, last) = Self::split(input);
+        if self.0.iter().any(|target| target == last) {
+            return Ok(());
+        }
+        let mut matches = self.matches(last);
+        match (matches.next(), matches.next()) {
+            (None, _) => Err(LookupError::NoMatch),
+            (Some(target), None) => Err(LookupError::OneMatch(format!("{head}{target}"))),
+            _ => Err(LookupError::ManyMatches),
+        }
+    }
+
+    fn longest_prefix(&self, input: &str) -> Option<String> {
+        let (head, last) = Self::split(input);
+        let prefix = utils::common_prefix(self.matches(last).map(String::as_str))?;
+        Some(format!("{head}{prefix}"))
+    }
+
+    fn all_lookups(&self, input: &str) -> Vec<String> {
+        let (_, last) = Self::split(input);
+        self.matches(last).cloned().collect()
+    }
+}
+
+/// Return a CSV table with a row per entity and columns for the entity, its
+/// name, and each field target like "Transform.translation.y".
+fn filter_query_csv(world: &World, entities: &[Entity], fields: &[String]) -> String {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let columns: Vec<_> = fields
+        .iter()
+        .map(|target| {
+            let (type_path, path) = split_field_target(target);
+            let reflect_component = registration(&type_registry, type_path)
+                .and_then(|registration| registration.data::<ReflectComponent>());
+            (reflect_component, path)
+        })
+        .collect();
+    let mut csv = ["entity", "name"]
+        .into_iter()
+        .chain(fields.iter().map(String::as_str))
+        .map(csv_field)
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+    for entity in entities {
+        let name = world
+            .get::<Name>(*entity)
+            .map(|name| name.as_str().to_string())
+            .unwrap_or_default();
+        let mut row = vec![csv_field(&entity.to_string()), csv_field(&name)];
+        for (reflect_component, path) in &columns {
+            let value = reflect_component
+                .and_then(|reflect_component| reflect_component.reflect(world.entity(*entity)))
+                .and_then(|component| path.reflect_element(component.as_partial_reflect()).ok())
+                .map(|field| match utils::as_f64(field) {
+                    Some(x) => x.to_string(),
+                    None => field
+                        .try_downcast_ref::<String>()
+                        .cloned()
+                        .unwrap_or_else(|| format!("{field:?}")),
+                })
+                .unwrap_or_default();
+            row.push(csv_field(&value));
+        }
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quote a CSV field if necessary.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
     } else {
-        minibuffer.message("No filter queries registered.");
+        field.to_string()
+    }
+}
+
+fn filter_query_next_page(
+    filters: Res<Inspectors<FilterQueryActs>>,
+    views: ResMut<FilterQueryViews>,
+    minibuffer: Minibuffer,
+) {
+    turn_page(filters, views, minibuffer, |page| page.saturating_add(1));
+}
+
+fn filter_query_previous_page(
+    filters: Res<Inspectors<FilterQueryActs>>,
+    views: ResMut<FilterQueryViews>,
+    minibuffer: Minibuffer,
+) {
+    turn_page(filters, views, minibuffer, |page| page.saturating_sub(1));
+}
+
+/// Turn the page of every visible filter query window. Pages past the end
+/// are clamped when shown.
+fn turn_page(
+    filters: Res<Inspectors<FilterQueryActs>>,
+    mut views: ResMut<FilterQueryViews>,
+    mut minibuffer: Minibuffer,
+    turn: impl Fn(usize) -> usize,
+) {
+    let mut turned = false;
+    for (view, visible) in views.views.iter_mut().zip(&filters.visible) {
+        if *visible {
+            view.page = turn(view.page);
+            turned = true;
+        }
+    }
+    if turned {
+        minibuffer.clear();
+    } else {
+        minibuffer.message("No filter queries visible.");
+    }
+}
+
+fn sort_filter_query(filters: Res<Inspectors<FilterQueryActs>>, minibuffer: Minibuffer) {
+    prompt_filter_fields(&filters, minibuffer, prompt_sort);
+}
+
+fn prompt_sort(
+    In((index, _entities, targets)): In<(usize, Vec<Entity>, Vec<String>)>,
+    mut minibuffer: Minibuffer,
+) {
+    let choices: Vec<String> = ["query", "entity", "Name"]
+        .into_iter()
+        .map(str::to_string)
+        .chain(targets)
+        .collect();
+    minibuffer.prompt_lookup("sort by: ", choices).observe(
+        move |mut trigger: On<Submit<String>>,
+              mut minibuffer: Minibuffer,
+              mut views: ResMut<FilterQueryViews>| {
+            match trigger.event_mut().take_result() {
+                Ok(input) => {
+                    let view = &mut views.views[index];
+                    view.sort = SortBy::parse(&input);
+                    view.sorted = None;
+                    view.page = 0;
+                    minibuffer.clear();
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn filter_query_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    let visible: Vec<usize> = world
+        .resource::<Inspectors<FilterQueryActs>>()
+        .visible
+        .iter()
+        .enumerate()
+        .filter_map(|(index, visible)| visible.then_some(index))
+        .collect();
+    for index in visible {
+        let matches = FilterQueries::entities(world, index);
+        let entities = FilterQueryView::sorted(world, index, matches);
+        let views = world.resource::<FilterQueryViews>();
+        let page_size = views.page_size;
+        let view = &views.views[index];
+        let (name, sort) = (view.name.clone(), view.sort.clone());
+        let pages = entities.len().div_ceil(page_size).max(1);
+        let mut page = view.page.min(pages - 1);
+
+        egui::Window::new(name.as_str())
+            .default_size((320., 160.))
+            .show(egui_context.get_mut(), |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("<").clicked() {
+                        page = page.saturating_sub(1);
+                    }
+                    ui.label(format!("{}/{pages}", page + 1));
+                    if ui.button(">").clicked() {
+                        page = (page + 1).min(pages - 1);
+                    }
+                    ui.label(format!("{} matches by {sort}", entities.len()));
+                });
+                egui::ScrollArea::both().show(ui, |ui| {
+                    for entity in entities.iter().skip(page * page_size).take(page_size) {
+                        let mut label = bevy_inspector::guess_entity_name(world, *entity);
+                        if let Some(annotation) =
+                            world.resource::<FilterQueries>().0[index].annotation(world, *entity)
+                        {
+                            label = format!("{label} ({annotation})");
+                        }
+                        egui::CollapsingHeader::new(label)
+                            .id_salt(entity)
+                            .show(ui, |ui| {
+                                let snapshots = EditHistory::snapshot_components(world, *entity);
+                                entity_ui(world, *entity, ui);
+                                if let Some(snapshots) = snapshots {
+                                    EditHistory::record_components(world, *entity, snapshots);
+                                }
+                            });
+                    }
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+        world.resource_mut::<FilterQueryViews>().views[index].page = page;
     }
 }
 
@@ -106,6 +958,112 @@ impl PluginGroup for FilterQueryActs {
         self.plugins.warn_on_empty(
             "No filter queries registered with `FilterQueryActs`; consider adding some.",
         );
-        self.plugins.build()
+        let mut plugins = self.plugins;
+        let queries = Mutex::new(Some(self.queries));
+        let views = FilterQueryViews {
+            page_size: self.page_size.max(1),
+            views: self
+                .names
+                .into_iter()
+                .map(|name| FilterQueryView {
+                    name,
+                    ..Default::default()
+                })
+                .collect(),
+        };
+        let views = Mutex::new(Some(views));
+        plugins.add_plugin(move |app: &mut App| {
+            if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+                app.add_plugins(DefaultInspectorConfigPlugin);
+            }
+            if let Some(queries) = queries.lock().unwrap().take() {
+                app.insert_resource(FilterQueries(queries));
+            }
+            if let Some(views) = views.lock().unwrap().take() {
+                app.insert_resource(views);
+            }
+            app.add_systems(
+                EguiPrimaryContextPass,
+                filter_query_ui.run_if(in_state(PromptState::Visible)),
+            );
+        });
+        plugins.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sort_by() {
+        assert_eq!(SortBy::parse("query"), SortBy::Query);
+        assert_eq!(SortBy::parse("entity"), SortBy::Entity);
+        assert_eq!(SortBy::parse("Name"), SortBy::Name);
+        assert_eq!(
+            SortBy::parse("Transform.translation.y"),
+            SortBy::Field("Transform.translation.y".into())
+        );
+        assert_eq!(SortBy::default(), SortBy::Entity);
+    }
+
+    #[test]
+    fn counted_names_label_completions() {
+        let names = CountedNames {
+            names: Trie::from_iter([("With<Mesh3d>", 0), ("With<Camera>", 1)]),
+            counts: vec![42, 1],
+        };
+        assert!(names.lookup("With<Mesh3d>").is_ok());
+        assert_eq!(names.resolve("With<Camera>"), Some(1));
+        assert_eq!(names.longest_prefix("W").as_deref(), Some("With<"));
+        assert_eq!(
+            names.all_lookups("With<"),
+            ["With<Camera> (1)", "With<Mesh3d> (42)"]
+        );
+    }
+
+    #[test]
+    fn field_list_completes_last_field() {
+        let fields = FieldList(vec![
+            "Transform.translation.x".into(),
+            "Transform.translation.y".into(),
+            "Name".into(),
+        ]);
+        assert_eq!(FieldList::split("Name, Trans"), ("Name, ", "Trans"));
+        assert!(fields.lookup("Name, Transform.translation.x").is_ok());
+        assert!(matches!(
+            fields.lookup("Transform.translation.x,Na"),
+            Err(LookupError::OneMatch(s)) if s == "Transform.translation.x,Name"
+        ));
+        assert_eq!(
+            fields.longest_prefix("Name, T").as_deref(),
+            Some("Name, Transform.translation.")
+        );
+        assert_eq!(
+            fields.all_lookups("Name, T"),
+            ["Transform.translation.x", "Transform.translation.y"]
+        );
+    }
+
+    #[test]
+    fn csv_field_plain() {
+        assert_eq!(csv_field("Player"), "Player");
+        assert_eq!(csv_field(""), "");
+    }
+
+    #[test]
+    fn csv_field_comma() {
+        assert_eq!(csv_field("1,2"), "\"1,2\"");
+    }
+
+    #[test]
+    fn csv_field_quote() {
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn csv_field_newline() {
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(csv_field("a\r\nb"), "\"a\r\nb\"");
     }
 }
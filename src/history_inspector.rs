@@ -0,0 +1,278 @@
+use crate::{
+    read_only::refuse_edit,
+    utils::{self, apply_component, clone_value, component_value, reflected_components},
+    RegisteredResources,
+};
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Commands, Mut, ResMut, Resource, World},
+    reflect::AppTypeRegistry,
+};
+use bevy_minibuffer::prelude::*;
+use bevy_reflect::PartialReflect;
+use std::{any::TypeId, collections::VecDeque};
+
+/// ## Adds the 'inspector_undo' and 'inspector_redo' acts
+///
+/// Edits made in the resource, state, and filter query inspector windows of
+/// this crate are recorded with their values before and after. The
+/// 'inspector_undo' act reverts the last edit, and the 'inspector_redo' act
+/// reapplies it. A drag across several frames counts as one edit. Resets made
+/// with the 'reset_to_default' act are recorded too.
+///
+/// Edits are only recorded if these acts are added.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::HistoryActs::default(),
+///         ));
+/// }
+/// ```
+pub struct HistoryActs {
+    acts: Acts,
+    capacity: usize,
+}
+
+impl ActsPlugin for HistoryActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl HistoryActs {
+    /// Set the number of edits that can be undone. The default is 100.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+}
+
+impl Default for HistoryActs {
+    fn default() -> Self {
+        Self {
+            acts: Acts::new([Act::new(inspector_undo), Act::new(inspector_redo)]),
+            capacity: 100,
+        }
+    }
+}
+
+impl Plugin for HistoryActs {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EditHistory {
+            capacity: self.capacity,
+            ..Default::default()
+        })
+        .add_systems(First, |mut history: ResMut<EditHistory>| {
+            history.frame = history.frame.wrapping_add(1)
+        });
+        self.warn_on_unused_acts();
+    }
+}
+
+/// Clones of an entity's reflected components with their names and types
+pub(crate) type ComponentSnapshots = Vec<(String, TypeId, Box<dyn PartialReflect>)>;
+
+/// Writes a state's reflected value as its next state.
+pub(crate) type WriteState = fn(&mut World, &dyn PartialReflect) -> Result<(), String>;
+
+/// What an edit changed.
+#[derive(Clone, Copy)]
+pub(crate) enum EditTarget {
+    /// A registered resource's index
+    Resource(usize),
+    State(TypeId, WriteState),
+    Component(Entity, TypeId),
+}
+
+impl EditTarget {
+    fn is(&self, other: &EditTarget) -> bool {
+        match (self, other) {
+            (EditTarget::Resource(a), EditTarget::Resource(b)) => a == b,
+            (EditTarget::State(a, _), EditTarget::State(b, _)) => a == b,
+            (EditTarget::Component(e, a), EditTarget::Component(f, b)) => e == f && a == b,
+            _ => false,
+        }
+    }
+
+    /// Apply a reflected value to the target.
+    pub(crate) fn write(
+        &self,
+        world: &mut World,
+        value: &dyn PartialReflect,
+    ) -> Result<(), String> {
+        match self {
+            EditTarget::Resource(index) => {
+                world.resource_scope(|world, resources: Mut<RegisteredResources>| {
+                    resources.0[*index].apply(world, value)
+                })
+            }
+            EditTarget::State(_, write) => write(world, value),
+            EditTarget::Component(entity, type_id) => {
+                apply_component(world, *entity, *type_id, value)
+            }
+        }
+    }
+}
+
+/// A recorded edit.
+struct Edit {
+    label: String,
+    target: EditTarget,
+    before: Box<dyn PartialReflect>,
+    after: Box<dyn PartialReflect>,
+    frame: u32,
+}
+
+/// The undo and redo history of edits.
+#[derive(Resource, Default)]
+pub(crate) struct EditHistory {
+    undo: VecDeque<Edit>,
+    redo: Vec<Edit>,
+    capacity: usize,
+    frame: u32,
+}
+
+impl EditHistory {
+    /// Record an edit unless `before` and `after` are equal. An edit of the
+    /// same target on consecutive frames extends the last edit.
+    pub(crate) fn record(
+        world: &mut World,
+        label: impl Into<String>,
+        target: EditTarget,
+        before: Box<dyn PartialReflect>,
+        after: &dyn PartialReflect,
+    ) {
+        if utils::diff_fields(before.as_ref(), after).is_empty() {
+            return;
+        }
+        let Some(mut history) = world.get_resource_mut::<EditHistory>() else {
+            return;
+        };
+        let frame = history.frame;
+        history.redo.clear();
+        if let Some(last) = history
+            .undo
+            .back_mut()
+            .filter(|last| last.target.is(&target) && frame.wrapping_sub(last.frame) <= 1)
+        {
+            last.after = clone_value(after);
+            last.frame = frame;
+            return;
+        }
+        while history.undo.len() >= history.capacity.max(1) {
+            history.undo.pop_front();
+        }
+        history.undo.push_back(Edit {
+            label: label.into(),
+            target,
+            before,
+            after: clone_value(after),
+            frame,
+        });
+    }
+
+    /// Return clones of an entity's reflected components if there is a
+    /// history to record their edits in.
+    pub(crate) fn snapshot_components(world: &World, entity: Entity) -> Option<ComponentSnapshots> {
+        if !world.contains_resource::<EditHistory>() {
+            return None;
+        }
+        let components = reflected_components(
+            entity,
+            world.entities(),
+            world.archetypes(),
+            world.components(),
+            &world.resource::<AppTypeRegistry>().read(),
+        );
+        Some(
+            components
+                .into_iter()
+                .filter_map(|(name, type_id)| {
+                    Some((name, type_id, component_value(world, entity, type_id)?))
+                })
+                .collect(),
+        )
+    }
+
+    /// Record edits of an entity's components since
+    /// [EditHistory::snapshot_components].
+    pub(crate) fn record_components(
+        world: &mut World,
+        entity: Entity,
+        snapshots: ComponentSnapshots,
+    ) {
+        for (name, type_id, before) in snapshots {
+            let Some(after) = component_value(world, entity, type_id) else {
+                continue;
+            };
+            EditHistory::record(
+                world,
+                format!("{name} on {entity}"),
+                EditTarget::Component(entity, type_id),
+                before,
+                after.as_ref(),
+            );
+        }
+    }
+}
+
+fn inspector_undo(mut commands: Commands) {
+    commands.queue(undo);
+}
+
+fn inspector_redo(mut commands: Commands) {
+    commands.queue(redo);
+}
+
+fn undo(world: &mut World) {
+    if refuse_edit(world) {
+        return;
+    }
+    let Some(edit) = world.resource_mut::<EditHistory>().undo.pop_back() else {
+        utils::message(world, "Nothing to undo.");
+        return;
+    };
+    match edit.target.write(world, edit.before.as_ref()) {
+        Ok(()) => {
+            utils::message(world, format!("Undid edit of {}.", edit.label));
+            world.resource_mut::<EditHistory>().redo.push(edit);
+        }
+        Err(e) => utils::message(world, e),
+    }
+}
+
+fn redo(world: &mut World) {
+    if refuse_edit(world) {
+        return;
+    }
+    let Some(edit) = world.resource_mut::<EditHistory>().redo.pop() else {
+        utils::message(world, "Nothing to redo.");
+        return;
+    };
+    match edit.target.write(world, edit.after.as_ref()) {
+        Ok(()) => {
+            utils::message(world, format!("Redid edit of {}.", edit.label));
+            let mut history = world.resource_mut::<EditHistory>();
+            // Don't merge a later edit into this one.
+            let edit = Edit {
+                frame: history.frame.wrapping_sub(2),
+                ..edit
+            };
+            history.undo.push_back(edit);
+        }
+        Err(e) => utils::message(world, e),
+    }
+}
@@ -14,4 +14,23 @@ mod state_inspector;
 pub use state_inspector::*;
 mod filter_query_inspector;
 pub use filter_query_inspector::*;
+mod entity_inspector;
+pub use entity_inspector::*;
+mod lifecycle_inspector;
+pub use lifecycle_inspector::*;
+mod query_data_inspector;
+pub use query_data_inspector::*;
+mod archetype_inspector;
+pub use archetype_inspector::*;
+mod watch_inspector;
+pub use watch_inspector::*;
+mod plot_inspector;
+pub use plot_inspector::*;
+mod history_inspector;
+pub use history_inspector::*;
+mod breakpoint_inspector;
+pub use breakpoint_inspector::*;
+mod read_only;
+pub use read_only::*;
+pub(crate) mod field_target;
 pub(crate) mod utils;
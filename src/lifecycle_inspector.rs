@@ -0,0 +1,225 @@
+use crate::utils::{entity_label, pretty_type_name};
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::{
+    component::{Component, Tick},
+    entity::EntityHashSet,
+    entity_disabling::Disabled,
+    lifecycle::{Add, Despawn, Remove},
+    name::Name,
+    prelude::{IntoScheduleConfigs, On, Query, Res, ResMut, Resource, With, World},
+    query::{Allow, SpawnDetails},
+    schedule::SystemCondition,
+    system::SystemChangeTick,
+};
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    egui,
+};
+use bevy_minibuffer::{prelude::*, prompt::PromptState};
+use bevy_state::prelude::in_state;
+use std::collections::VecDeque;
+
+/// ## Adds the 'inspect_lifecycle' act
+///
+/// This act toggles the visibility of a rolling log of entity spawns and
+/// despawns. Components registered with [LifecycleActs::add] also have their
+/// additions and removals logged.
+///
+/// The log is only kept if these acts are added. Entities spawned without any
+/// components are not logged.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::LifecycleActs::default()
+///                 .add::<Visibility>()
+///         ));
+/// }
+/// ```
+pub struct LifecycleActs {
+    acts: Acts,
+    capacity: usize,
+    observers: Vec<fn(&mut App)>,
+}
+
+impl ActsPlugin for LifecycleActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl LifecycleActs {
+    /// Log when this component is added to or removed from an entity.
+    pub fn add<C: Component>(mut self) -> Self {
+        self.observers.push(Self::observe_component::<C>);
+        self
+    }
+
+    /// Set the number of entries kept in the log. The default is 256.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    fn observe_component<C: Component>(app: &mut App) {
+        app.add_observer(
+            |add: On<Add, C>, mut log: ResMut<LifecycleLog>, names: Query<Option<&Name>>| {
+                let name = names.get(add.entity).ok().flatten();
+                log.push(format!(
+                    "{} added to {}",
+                    pretty_type_name::<C>(),
+                    entity_label(add.entity, name)
+                ));
+            },
+        )
+        .add_observer(
+            |remove: On<Remove, C>, mut log: ResMut<LifecycleLog>, names: Query<Option<&Name>>| {
+                // Removals due to a despawn are covered by the despawn entry.
+                if log.despawning.contains(&remove.entity) {
+                    return;
+                }
+                let name = names.get(remove.entity).ok().flatten();
+                log.push(format!(
+                    "{} removed from {}",
+                    pretty_type_name::<C>(),
+                    entity_label(remove.entity, name)
+                ));
+            },
+        );
+    }
+}
+
+impl Default for LifecycleActs {
+    fn default() -> Self {
+        Self {
+            acts: Acts::new([Act::new(inspect_lifecycle)]),
+            capacity: 256,
+            observers: vec![],
+        }
+    }
+}
+
+impl Plugin for LifecycleActs {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(LifecycleLog {
+            capacity: self.capacity,
+            ..Default::default()
+        })
+        .add_systems(First, start_frame)
+        .add_observer(log_spawn)
+        .add_observer(log_despawn)
+        .add_systems(
+            EguiPrimaryContextPass,
+            lifecycle_ui
+                .run_if(in_state(PromptState::Visible).and(|log: Res<LifecycleLog>| log.visible)),
+        );
+        for observe in &self.observers {
+            observe(app);
+        }
+        self.warn_on_unused_acts();
+    }
+}
+
+/// A rolling log of entity lifecycle events.
+#[derive(Resource, Default)]
+pub(crate) struct LifecycleLog {
+    entries: VecDeque<(u32, String)>,
+    capacity: usize,
+    frame: u32,
+    visible: bool,
+    /// The change tick when this frame started
+    since: Tick,
+    /// Entities logged as spawned this frame
+    spawned: EntityHashSet,
+    /// Entities despawned this frame
+    despawning: EntityHashSet,
+}
+
+impl LifecycleLog {
+    fn push(&mut self, entry: String) {
+        while self.entries.len() >= self.capacity.max(1) {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((self.frame, entry));
+    }
+}
+
+fn start_frame(mut log: ResMut<LifecycleLog>, ticks: SystemChangeTick) {
+    log.frame += 1;
+    log.since = ticks.this_run();
+    log.spawned.clear();
+    log.despawning.clear();
+}
+
+/// Log an entity's spawn when its first components are added.
+fn log_spawn(
+    add: On<Add>,
+    entities: Query<(SpawnDetails, Option<&Name>), Allow<Disabled>>,
+    mut log: ResMut<LifecycleLog>,
+    ticks: SystemChangeTick,
+) {
+    let Ok((details, name)) = entities.get(add.entity) else {
+        return;
+    };
+    // Components added to an entity spawned in an earlier frame are not a spawn.
+    let spawned = details
+        .spawn_tick()
+        .is_newer_than(log.since, ticks.this_run());
+    if !spawned || !log.spawned.insert(add.entity) {
+        return;
+    }
+    log.push(format!("spawned {}", entity_label(add.entity, name)));
+}
+
+fn log_despawn(
+    despawn: On<Despawn>,
+    names: Query<Option<&Name>, Allow<Disabled>>,
+    mut log: ResMut<LifecycleLog>,
+) {
+    if !log.despawning.insert(despawn.entity) {
+        return;
+    }
+    let name = names.get(despawn.entity).ok().flatten();
+    log.push(format!("despawned {}", entity_label(despawn.entity, name)));
+}
+
+fn inspect_lifecycle(mut log: ResMut<LifecycleLog>, mut minibuffer: Minibuffer) {
+    log.visible = !log.visible;
+    minibuffer.clear();
+}
+
+fn lifecycle_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let log = world.resource::<LifecycleLog>();
+
+    egui::Window::new("Lifecycle")
+        .default_size((320., 160.))
+        .show(egui_context.get_mut(), |ui| {
+            egui::ScrollArea::both()
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    for (frame, entry) in &log.entries {
+                        ui.monospace(format!("{frame:>6} {entry}"));
+                    }
+                    ui.allocate_space(ui.available_size());
+                });
+        });
+}
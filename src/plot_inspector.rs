@@ -0,0 +1,211 @@
+use crate::{
+    field_target::{prompt_field_target, FieldTarget},
+    utils,
+};
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::{
+    prelude::{Commands, In, IntoScheduleConfigs, Mut, Res, Resource, With, World},
+    schedule::SystemCondition,
+};
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    egui,
+};
+use bevy_minibuffer::{prelude::*, prompt::PromptState};
+use bevy_state::prelude::in_state;
+use std::collections::VecDeque;
+
+/// ## Adds the 'plot_field' act
+///
+/// This act prompts for a numeric field of a resource registered with
+/// [ResourceActs](crate::ResourceActs) like "Configuration.option" or an entity
+/// and one of its components' fields like "Transform.translation.y". The field
+/// is sampled every frame and drawn as a line plot, which makes oscillations
+/// and spikes easy to spot. Each plot's window can pause, clear, or close it.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::PlotActs::default(),
+///         ));
+/// }
+/// ```
+pub struct PlotActs {
+    acts: Acts,
+    capacity: usize,
+}
+
+impl ActsPlugin for PlotActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl PlotActs {
+    /// Set the number of samples kept per plot. The default is 600.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+}
+
+impl Default for PlotActs {
+    fn default() -> Self {
+        Self {
+            acts: Acts::new([Act::new(plot_field)]),
+            capacity: 600,
+        }
+    }
+}
+
+impl Plugin for PlotActs {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Plots {
+            capacity: self.capacity.max(2),
+            plots: vec![],
+        })
+        .add_systems(
+            Last,
+            sample_plots.run_if(|plots: Res<Plots>| !plots.plots.is_empty()),
+        )
+        .add_systems(
+            EguiPrimaryContextPass,
+            plot_ui.run_if(
+                in_state(PromptState::Visible).and(|plots: Res<Plots>| !plots.plots.is_empty()),
+            ),
+        );
+        self.warn_on_unused_acts();
+    }
+}
+
+/// A plotted field and its samples.
+struct Plot {
+    target: FieldTarget,
+    samples: VecDeque<f64>,
+    paused: bool,
+}
+
+/// The plotted fields.
+#[derive(Resource)]
+struct Plots {
+    capacity: usize,
+    plots: Vec<Plot>,
+}
+
+fn plot_field(mut commands: Commands) {
+    prompt_field_target(&mut commands, "plot: ", add_plot);
+}
+
+fn add_plot(In(target): In<FieldTarget>, world: &mut World) {
+    let numeric = target
+        .read(world)
+        .is_ok_and(|value| utils::as_f64(value).is_some());
+    if !numeric {
+        utils::message(world, format!("{} is not numeric.", target.label));
+        return;
+    }
+    world.resource_mut::<Plots>().plots.push(Plot {
+        target,
+        samples: VecDeque::new(),
+        paused: false,
+    });
+    utils::message(world, "Plotting.");
+}
+
+fn sample_plots(world: &mut World) {
+    world.resource_scope(|world, mut plots: Mut<Plots>| {
+        let capacity = plots.capacity;
+        for plot in plots.plots.iter_mut().filter(|plot| !plot.paused) {
+            let Some(sample) = plot.target.read(world).ok().and_then(utils::as_f64) else {
+                continue;
+            };
+            while plot.samples.len() >= capacity {
+                plot.samples.pop_front();
+            }
+            plot.samples.push_back(sample);
+        }
+    });
+}
+
+fn plot_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let mut plots = world.resource_mut::<Plots>();
+    let capacity = plots.capacity;
+
+    let mut closed = vec![];
+    for (index, plot) in plots.plots.iter_mut().enumerate() {
+        let mut open = true;
+        egui::Window::new(plot.target.label.as_str())
+            .id(egui::Id::new(("plot", index)))
+            .open(&mut open)
+            .default_size((320., 160.))
+            .show(egui_context.get_mut(), |ui| {
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(if plot.paused { "Resume" } else { "Pause" })
+                        .clicked()
+                    {
+                        plot.paused = !plot.paused;
+                    }
+                    if ui.button("Clear").clicked() {
+                        plot.samples.clear();
+                    }
+                });
+                let min = plot.samples.iter().copied().fold(f64::INFINITY, f64::min);
+                let max = plot
+                    .samples
+                    .iter()
+                    .copied()
+                    .fold(f64::NEG_INFINITY, f64::max);
+                if let Some(last) = plot.samples.back() {
+                    ui.monospace(format!("{last:.4} (min {min:.4}, max {max:.4})"));
+                }
+                let size = egui::vec2(ui.available_width().max(240.), 120.);
+                let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+                let rect = response.rect;
+                painter.rect_filled(rect, 2., ui.visuals().extreme_bg_color);
+                if plot.samples.len() >= 2 {
+                    let span = (max - min).max(f64::EPSILON);
+                    let points = plot
+                        .samples
+                        .iter()
+                        .enumerate()
+                        .map(|(i, sample)| {
+                            egui::pos2(
+                                rect.left() + rect.width() * i as f32 / (capacity - 1) as f32,
+                                rect.bottom() - rect.height() * ((sample - min) / span) as f32,
+                            )
+                        })
+                        .collect();
+                    painter.add(egui::Shape::line(
+                        points,
+                        egui::Stroke::new(1.5, ui.visuals().strong_text_color()),
+                    ));
+                }
+            });
+        if !open {
+            closed.push(index);
+        }
+    }
+    for index in closed.iter().rev() {
+        plots.plots.remove(*index);
+    }
+}
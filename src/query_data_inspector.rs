@@ -0,0 +1,352 @@
+use crate::{
+    utils::{self, pretty_type_name, SortKey},
+    InspectorPlugins, Inspectors, ReadOnlyInspectors,
+};
+use bevy_app::{App, PluginGroup, PluginGroupBuilder};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    entity::Entity,
+    prelude::{IntoScheduleConfigs, Local, On, Res, ResMut, With, World},
+    query::{ComponentAccessKind, QueryState, ReadOnlyQueryData},
+    reflect::{AppTypeRegistry, ReflectComponent},
+    schedule::SystemCondition,
+};
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    egui, reflect_inspector,
+};
+use bevy_minibuffer::{prelude::*, prompt::PromptState};
+use bevy_reflect::{PartialReflect, ReflectPath, ReflectRef, TypeRegistry};
+use bevy_state::prelude::in_state;
+
+/// ## Adds the 'inspect_query_data' act
+///
+/// This act toggles the visibility of tables for the added query data. Each
+/// table has one row per matching entity and one column per component field.
+/// Cells are editable unless [ReadOnlyInspectors] is present, and clicking a
+/// column header sorts by that column.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::QueryDataActs::default()
+///                 .add::<(&Transform, &Name)>()
+///         ));
+/// }
+/// ```
+pub struct QueryDataActs {
+    plugins: InspectorPlugins<Self>,
+    acts: Acts,
+}
+
+impl ActsPluginGroup for QueryDataActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl QueryDataActs {
+    /// Add query data to show as a table. Its reflected components become the
+    /// table's columns.
+    pub fn add<D: ReadOnlyQueryData + 'static>(mut self) -> Self {
+        self.plugins
+            .add_inspector(pretty_type_name::<D>(), Self::query_data_table_plugin::<D>);
+        self
+    }
+
+    fn query_data_table_plugin<D: ReadOnlyQueryData + 'static>(
+        index: usize,
+        inspector_plugins: &mut InspectorPlugins<Self>,
+    ) {
+        inspector_plugins.add_plugin(move |app: &mut App| {
+            app.add_systems(
+                EguiPrimaryContextPass,
+                query_data_table_ui::<D>.run_if(
+                    in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
+                ),
+            );
+        });
+    }
+}
+
+impl Default for QueryDataActs {
+    fn default() -> Self {
+        Self {
+            plugins: InspectorPlugins::default(),
+            acts: Acts::new([Act::new(inspect_query_data)]),
+        }
+    }
+}
+
+fn inspect_query_data(queries: Res<Inspectors<QueryDataActs>>, mut minibuffer: Minibuffer) {
+    if !queries.visible.is_empty() {
+        minibuffer
+            .prompt_map("query data: ", queries.names.clone())
+            .observe(
+                |mut trigger: On<Completed<usize>>,
+                 mut minibuffer: Minibuffer,
+                 mut queries: ResMut<Inspectors<QueryDataActs>>| {
+                    match trigger.event_mut().state.take_result().unwrap() {
+                        Ok(index) => {
+                            queries.visible[index] = !queries.visible[index];
+                            minibuffer.clear();
+                        }
+                        Err(e) => {
+                            minibuffer.message(format!("{e}"));
+                        }
+                    }
+                },
+            );
+    } else {
+        minibuffer.message("No query data registered.");
+    }
+}
+
+impl PluginGroup for QueryDataActs {
+    fn build(self) -> PluginGroupBuilder {
+        self.warn_on_unused_acts();
+        self.plugins
+            .warn_on_empty("No query data registered with `QueryDataActs`; consider adding some.");
+        self.plugins.build()
+    }
+}
+
+/// A table column: a component's field.
+struct Column {
+    header: String,
+    reflect_component: ReflectComponent,
+    path: String,
+}
+
+/// The table's persistent state.
+struct Table<D: ReadOnlyQueryData + 'static> {
+    query: Option<QueryState<(Entity, D)>>,
+    /// Sort by this column, or by entity if `None`
+    sort: Option<usize>,
+    descending: bool,
+}
+
+impl<D: ReadOnlyQueryData + 'static> Default for Table<D> {
+    fn default() -> Self {
+        Self {
+            query: None,
+            sort: None,
+            descending: false,
+        }
+    }
+}
+
+fn query_data_table_ui<D: ReadOnlyQueryData + 'static>(
+    world: &mut World,
+    mut table: Local<Table<D>>,
+) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+
+    let query = table
+        .query
+        .get_or_insert_with(|| world.query::<(Entity, D)>());
+    let mut entities: Vec<Entity> = query.iter(world).map(|(entity, _)| entity).collect();
+    let columns = columns(world, query, entities.first().copied());
+
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+    let key = |world: &World, entity: Entity, column: &Column| -> Option<SortKey> {
+        let component = column.reflect_component.reflect(world.entity(entity))?;
+        let field = column
+            .path
+            .as_str()
+            .reflect_element(component.as_partial_reflect())
+            .ok()?;
+        Some(utils::sort_key(field))
+    };
+    match table.sort.and_then(|index| columns.get(index)) {
+        Some(column) => {
+            entities.sort_by_cached_key(|entity| key(world, *entity, column));
+        }
+        None => entities.sort(),
+    }
+    if table.descending {
+        entities.reverse();
+    }
+    let read_only = world.contains_resource::<ReadOnlyInspectors>();
+
+    egui::Window::new(pretty_type_name::<D>())
+        .default_size((320., 160.))
+        .show(egui_context.get_mut(), |ui| {
+            ui.label(format!("{} entities", entities.len()));
+            egui::ScrollArea::both().show(ui, |ui| {
+                egui::Grid::new(pretty_type_name::<D>())
+                    .striped(true)
+                    .show(ui, |ui| {
+                        let headers = std::iter::once("Entity")
+                            .chain(columns.iter().map(|column| column.header.as_str()));
+                        for (i, header) in headers.enumerate() {
+                            let sort = i.checked_sub(1);
+                            let arrow = match (table.sort == sort, table.descending) {
+                                (true, false) => " ^",
+                                (true, true) => " v",
+                                _ => "",
+                            };
+                            if ui.button(format!("{header}{arrow}")).clicked() {
+                                if table.sort == sort {
+                                    table.descending = !table.descending;
+                                } else {
+                                    table.sort = sort;
+                                    table.descending = false;
+                                }
+                            }
+                        }
+                        ui.end_row();
+                        for entity in &entities {
+                            ui.label(format!("{entity}"));
+                            for (index, column) in columns.iter().enumerate() {
+                                // Cells would otherwise share egui ids across rows.
+                                ui.push_id((*entity, index), |ui| {
+                                    cell_ui(world, column, *entity, read_only, &type_registry, ui)
+                                });
+                            }
+                            ui.end_row();
+                        }
+                    });
+                ui.allocate_space(ui.available_size());
+            });
+        });
+}
+
+/// Show or edit a column's field of `entity` in a table cell.
+fn cell_ui(
+    world: &mut World,
+    column: &Column,
+    entity: Entity,
+    read_only: bool,
+    type_registry: &TypeRegistry,
+    ui: &mut egui::Ui,
+) {
+    if read_only {
+        let field = column
+            .reflect_component
+            .reflect(world.entity(entity))
+            .and_then(|component| {
+                column
+                    .path
+                    .as_str()
+                    .reflect_element(component.as_partial_reflect())
+                    .ok()
+            });
+        match field {
+            Some(field) => reflect_inspector::ui_for_value_readonly(field, ui, type_registry),
+            None => {
+                ui.label("");
+            }
+        }
+        return;
+    }
+    let Some(mut component) = column
+        .reflect_component
+        .reflect_mut(world.entity_mut(entity))
+    else {
+        ui.label("");
+        return;
+    };
+    let changed = match column
+        .path
+        .as_str()
+        .reflect_element_mut(component.bypass_change_detection().as_partial_reflect_mut())
+    {
+        Ok(field) => reflect_inspector::ui_for_value(field, ui, type_registry),
+        Err(_) => {
+            ui.label("");
+            false
+        }
+    };
+    if changed {
+        component.set_changed();
+    }
+}
+
+/// Return the columns for the reflected components the query reads, using
+/// `entity` to discover their fields.
+fn columns<D: ReadOnlyQueryData>(
+    world: &World,
+    query: &QueryState<(Entity, D)>,
+    entity: Option<Entity>,
+) -> Vec<Column> {
+    let Some(entity) = entity else {
+        return vec![];
+    };
+    let Ok(access) = query
+        .component_access()
+        .access()
+        .try_iter_component_access()
+    else {
+        return vec![];
+    };
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut columns = vec![];
+    for kind in access {
+        let ComponentAccessKind::Shared(id) = kind else {
+            continue;
+        };
+        let Some(registration) = world
+            .components()
+            .get_info(id)
+            .and_then(|info| info.type_id())
+            .and_then(|type_id| type_registry.get(type_id))
+        else {
+            continue;
+        };
+        let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+            continue;
+        };
+        let Some(component) = reflect_component.reflect(world.entity(entity)) else {
+            continue;
+        };
+        let name = registration.type_info().type_path_table().short_path();
+        let paths = top_level_paths(component.as_partial_reflect());
+        if paths.is_empty() {
+            columns.push(Column {
+                header: name.to_string(),
+                reflect_component: reflect_component.clone(),
+                path: String::new(),
+            });
+        }
+        for path in paths {
+            columns.push(Column {
+                header: format!("{name}{path}"),
+                reflect_component: reflect_component.clone(),
+                path,
+            });
+        }
+    }
+    columns
+}
+
+/// Return the paths of a value's immediate fields like ".translation".
+fn top_level_paths(value: &dyn PartialReflect) -> Vec<String> {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len())
+            .filter_map(|i| value.name_at(i).map(|name| format!(".{name}")))
+            .collect(),
+        ReflectRef::TupleStruct(value) => (0..value.field_len()).map(|i| format!(".{i}")).collect(),
+        _ => vec![],
+    }
+}
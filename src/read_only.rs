@@ -0,0 +1,96 @@
+use crate::utils::{self, reflected_components};
+use bevy_ecs::{
+    entity::Entity,
+    prelude::{Resource, World},
+    reflect::{AppTypeRegistry, ReflectComponent},
+};
+use bevy_inspector_egui::{bevy_inspector, egui, reflect_inspector};
+use bevy_reflect::PartialReflect;
+
+/// ## Makes every inspector read-only
+///
+/// While this resource is present, the inspector windows of this crate show
+/// values without allowing edits, and acts that change values, like
+/// 'insert_resource', 'remove_component', or 'set_filter_query_field', refuse
+/// with a message. Individual resources, states, and assets can be made
+/// read-only instead with `add_read_only()` on their `*Acts` builder.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .insert_resource(inspector::ReadOnlyInspectors)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::WorldActs::default(),
+///         ));
+/// }
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct ReadOnlyInspectors;
+
+/// The message shown when an edit is refused.
+pub(crate) const READ_ONLY_MSG: &str = "Inspectors are read-only.";
+
+/// Return true if the inspectors are read-only. Show a message saying so.
+pub(crate) fn refuse_edit(world: &mut World) -> bool {
+    let read_only = world.contains_resource::<ReadOnlyInspectors>();
+    if read_only {
+        utils::message(world, READ_ONLY_MSG);
+    }
+    read_only
+}
+
+/// Show a reflected value without allowing edits.
+pub(crate) fn readonly_value_ui(world: &World, value: &dyn PartialReflect, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    reflect_inspector::ui_for_value_readonly(value, ui, &type_registry);
+}
+
+/// Show an entity's reflected components without allowing edits.
+pub(crate) fn readonly_entity_ui(world: &World, entity: Entity, ui: &mut egui::Ui) {
+    let Ok(entity_ref) = world.get_entity(entity) else {
+        ui.label(format!("{entity} does not exist."));
+        return;
+    };
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let components = reflected_components(
+        entity,
+        world.entities(),
+        world.archetypes(),
+        world.components(),
+        &type_registry,
+    );
+    for (name, type_id) in components {
+        let Some(value) = type_registry
+            .get_type_data::<ReflectComponent>(type_id)
+            .and_then(|reflect_component| reflect_component.reflect(entity_ref))
+        else {
+            continue;
+        };
+        egui::CollapsingHeader::new(name)
+            .id_salt((entity, type_id))
+            .show(ui, |ui| {
+                reflect_inspector::ui_for_value_readonly(
+                    value.as_partial_reflect(),
+                    ui,
+                    &type_registry,
+                );
+            });
+    }
+}
+
+/// Show an entity's components, allowing edits unless the inspectors are
+/// read-only.
+pub(crate) fn entity_ui(world: &mut World, entity: Entity, ui: &mut egui::Ui) {
+    if world.contains_resource::<ReadOnlyInspectors>() {
+        readonly_entity_ui(world, entity, ui);
+    } else {
+        bevy_inspector::ui_for_entity(world, entity, ui);
+    }
+}
@@ -1,17 +1,75 @@
-use crate::{utils::pretty_type_name, InspectorPlugins, Inspectors};
-use bevy_app::{PluginGroup, PluginGroupBuilder};
+use crate::{
+    read_only::{readonly_value_ui, refuse_edit, READ_ONLY_MSG},
+    utils::{self, pretty_type_name, RonPaths},
+    EditHistory, EditTarget, InspectorPlugins, Inspectors, ReadOnlyInspectors,
+};
+use bevy_app::{App, Last, PluginGroup, PluginGroupBuilder};
 use bevy_ecs::{
-    prelude::{On, Res, ResMut, Resource},
+    change_detection::DetectChangesMut,
+    prelude::{
+        Commands, In, IntoScheduleConfigs, IntoSystem, Local, Mut, On, Res, ResMut, Resource, With,
+        World,
+    },
+    reflect::AppTypeRegistry,
     schedule::SystemCondition,
 };
-use bevy_inspector_egui::quick::ResourceInspectorPlugin;
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    bevy_inspector, egui, reflect_inspector, DefaultInspectorConfigPlugin,
+};
+use bevy_log::warn;
 use bevy_minibuffer::{prelude::*, prompt::PromptState};
-use bevy_reflect::Reflect;
+use bevy_reflect::{
+    serde::{
+        ReflectDeserializer, ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer,
+    },
+    PartialReflect, Reflect,
+};
 use bevy_state::prelude::in_state;
+use ron::ser::PrettyConfig;
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    fmt::Debug,
+    fs,
+    marker::PhantomData,
+    path::{Component, Path, PathBuf},
+    sync::Mutex,
+};
+use trie_rs::map::Trie;
 
-/// ## Adds the 'inspect_resource' act
+/// ## Adds the 'inspect_resource', 'insert_resource', 'remove_resource', preset, snapshot, file, and notification acts
+///
+/// The 'inspect_resource' act toggles the visibility of resource inspectors
+/// that were added. Resources missing from the world are marked like
+/// "Configuration (missing)".
+///
+/// The 'insert_resource' act prompts for a missing resource and inserts its
+/// default value, built from its reflected `Default` or `FromWorld`. The
+/// 'remove_resource' act prompts for a present resource and removes it.
+///
+/// The 'save_resource_preset' act saves a resource's value under a name as a
+/// RON file in the [ResourceActs::preset_directory], and the
+/// 'load_resource_preset' act applies a saved preset back.
+///
+/// The 'snapshot_resource' act captures a resource's value, and the
+/// 'diff_resource' act lists the fields that changed since then as
+/// ".field: old → new".
+///
+/// The 'save_resource' act writes a resource to a RON file, and the
+/// 'load_resource' act reads one back, applying it to the resource it names.
+/// Both complete the path one directory at a time, offering RON files.
 ///
-/// This act toggles the visibility of resource inspectors that were added.
+/// The 'notify_resource_change' act turns on a message in the minibuffer when
+/// a resource changes, showing its name or its changed fields, or turns it
+/// off.
+///
+/// Resources added with [ResourceActs::add_read_only] are shown without
+/// allowing edits and are not offered to the acts that change them. Resources
+/// that don't implement `Reflect` can be added with [ResourceActs::add_debug]
+/// to show their `Debug` output. Non-send resources can be added with
+/// [ResourceActs::add_non_send] or [ResourceActs::add_non_send_debug]; their
+/// inspectors run on the main thread.
 ///
 /// ## Usage
 ///
@@ -36,9 +94,387 @@ use bevy_state::prelude::in_state;
 /// ```
 pub struct ResourceActs {
     plugins: InspectorPlugins<Self>,
+    resources: Vec<Box<dyn RegisteredResource>>,
+    preset_directory: PathBuf,
     acts: Acts,
 }
 
+/// Type-erased access to a registered resource.
+pub(crate) trait RegisteredResource: Send + Sync + 'static {
+    /// Return the resource's name.
+    fn name(&self) -> String;
+
+    /// Return the resource's type id.
+    fn resource_type_id(&self) -> TypeId;
+
+    /// Return true if the resource is in the world.
+    fn contains(&self, world: &World) -> bool;
+
+    /// Return true if the resource was added or changed since the calling
+    /// exclusive system last ran.
+    fn is_changed(&self, world: &World) -> bool;
+
+    /// Return the resource's reflected value if it is in the world.
+    fn reflect<'w>(&self, world: &'w World) -> Option<&'w dyn Reflect>;
+
+    /// Insert a reflected value as the resource.
+    fn insert(&self, world: &mut World, value: Box<dyn Reflect>) -> Result<(), String>;
+
+    /// Remove the resource from the world.
+    fn remove(&self, world: &mut World);
+
+    /// Apply a reflected value to the resource in place.
+    fn apply(&self, world: &mut World, value: &dyn PartialReflect) -> Result<(), String>;
+
+    /// Return true if the resource must not be edited.
+    fn read_only(&self) -> bool;
+
+    /// Return true if the resource's value is reflected.
+    fn reflected(&self) -> bool;
+}
+
+struct Registered<R: Resource> {
+    read_only: bool,
+    marker: PhantomData<R>,
+}
+
+impl<R: Resource + Reflect> RegisteredResource for Registered<R> {
+    fn name(&self) -> String {
+        pretty_type_name::<R>()
+    }
+
+    fn resource_type_id(&self) -> TypeId {
+        TypeId::of::<R>()
+    }
+
+    fn contains(&self, world: &World) -> bool {
+        world.contains_resource::<R>()
+    }
+
+    fn is_changed(&self, world: &World) -> bool {
+        world.is_resource_changed::<R>()
+    }
+
+    fn reflect<'w>(&self, world: &'w World) -> Option<&'w dyn Reflect> {
+        world
+            .get_resource::<R>()
+            .map(|resource| resource as &dyn Reflect)
+    }
+
+    fn insert(&self, world: &mut World, value: Box<dyn Reflect>) -> Result<(), String> {
+        let value = value
+            .downcast::<R>()
+            .map_err(|_| format!("Value is not a {}.", self.name()))?;
+        world.insert_resource(*value);
+        Ok(())
+    }
+
+    fn remove(&self, world: &mut World) {
+        world.remove_resource::<R>();
+    }
+
+    fn apply(&self, world: &mut World, value: &dyn PartialReflect) -> Result<(), String> {
+        let mut resource = world
+            .get_resource_mut::<R>()
+            .ok_or_else(|| format!("{} is missing.", self.name()))?;
+        resource.try_apply(value).map_err(|e| format!("{e}"))
+    }
+
+    fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn reflected(&self) -> bool {
+        true
+    }
+}
+
+/// Return true if a non-send resource was added or changed since the calling
+/// exclusive system last ran.
+fn is_non_send_changed<R: 'static>(world: &World) -> bool {
+    world
+        .components()
+        .get_id(TypeId::of::<R>())
+        .and_then(|id| world.storages().non_send_resources.get(id))
+        .and_then(|data| data.get_ticks())
+        .is_some_and(|ticks| ticks.is_changed(world.last_change_tick(), world.read_change_tick()))
+}
+
+/// A non-send resource.
+struct RegisteredNonSend<R>(PhantomData<R>);
+
+impl<R: Reflect> RegisteredResource for RegisteredNonSend<R> {
+    fn name(&self) -> String {
+        pretty_type_name::<R>()
+    }
+
+    fn resource_type_id(&self) -> TypeId {
+        TypeId::of::<R>()
+    }
+
+    fn contains(&self, world: &World) -> bool {
+        world.contains_non_send::<R>()
+    }
+
+    fn is_changed(&self, world: &World) -> bool {
+        is_non_send_changed::<R>(world)
+    }
+
+    fn reflect<'w>(&self, world: &'w World) -> Option<&'w dyn Reflect> {
+        world
+            .get_non_send_resource::<R>()
+            .map(|resource| resource as &dyn Reflect)
+    }
+
+    fn insert(&self, world: &mut World, value: Box<dyn Reflect>) -> Result<(), String> {
+        let value = value
+            .downcast::<R>()
+            .map_err(|_| format!("Value is not a {}.", self.name()))?;
+        world.insert_non_send_resource(*value);
+        Ok(())
+    }
+
+    fn remove(&self, world: &mut World) {
+        world.remove_non_send_resource::<R>();
+    }
+
+    fn apply(&self, world: &mut World, value: &dyn PartialReflect) -> Result<(), String> {
+        let mut resource = world
+            .get_non_send_resource_mut::<R>()
+            .ok_or_else(|| format!("{} is missing.", self.name()))?;
+        resource.try_apply(value).map_err(|e| format!("{e}"))
+    }
+
+    fn read_only(&self) -> bool {
+        false
+    }
+
+    fn reflected(&self) -> bool {
+        true
+    }
+}
+
+/// A non-send resource shown by its `Debug` output. The marker is `Send` and
+/// `Sync` even if `R` isn't.
+struct RegisteredNonSendDebug<R>(PhantomData<fn() -> R>);
+
+impl<R: Debug + 'static> RegisteredResource for RegisteredNonSendDebug<R> {
+    fn name(&self) -> String {
+        pretty_type_name::<R>()
+    }
+
+    fn resource_type_id(&self) -> TypeId {
+        TypeId::of::<R>()
+    }
+
+    fn contains(&self, world: &World) -> bool {
+        world.contains_non_send::<R>()
+    }
+
+    fn is_changed(&self, world: &World) -> bool {
+        is_non_send_changed::<R>(world)
+    }
+
+    fn reflect<'w>(&self, _world: &'w World) -> Option<&'w dyn Reflect> {
+        None
+    }
+
+    fn insert(&self, _world: &mut World, _value: Box<dyn Reflect>) -> Result<(), String> {
+        Err(format!("{} is not reflected.", self.name()))
+    }
+
+    fn remove(&self, world: &mut World) {
+        world.remove_non_send_resource::<R>();
+    }
+
+    fn apply(&self, _world: &mut World, _value: &dyn PartialReflect) -> Result<(), String> {
+        Err(format!("{} is not reflected.", self.name()))
+    }
+
+    fn read_only(&self) -> bool {
+        true
+    }
+
+    fn reflected(&self) -> bool {
+        false
+    }
+}
+
+/// A resource shown by its `Debug` output.
+struct RegisteredDebug<R: Resource>(PhantomData<R>);
+
+impl<R: Resource + Debug> RegisteredResource for RegisteredDebug<R> {
+    fn name(&self) -> String {
+        pretty_type_name::<R>()
+    }
+
+    fn resource_type_id(&self) -> TypeId {
+        TypeId::of::<R>()
+    }
+
+    fn contains(&self, world: &World) -> bool {
+        world.contains_resource::<R>()
+    }
+
+    fn is_changed(&self, world: &World) -> bool {
+        world.is_resource_changed::<R>()
+    }
+
+    fn reflect<'w>(&self, _world: &'w World) -> Option<&'w dyn Reflect> {
+        None
+    }
+
+    fn insert(&self, _world: &mut World, _value: Box<dyn Reflect>) -> Result<(), String> {
+        Err(format!("{} is not reflected.", self.name()))
+    }
+
+    fn remove(&self, world: &mut World) {
+        world.remove_resource::<R>();
+    }
+
+    fn apply(&self, _world: &mut World, _value: &dyn PartialReflect) -> Result<(), String> {
+        Err(format!("{} is not reflected.", self.name()))
+    }
+
+    fn read_only(&self) -> bool {
+        true
+    }
+
+    fn reflected(&self) -> bool {
+        false
+    }
+}
+
+/// The registered resources indexed like [Inspectors].
+#[derive(Resource)]
+pub(crate) struct RegisteredResources(pub(crate) Vec<Box<dyn RegisteredResource>>);
+
+impl RegisteredResources {
+    /// Return the names and indices of the registered resources that satisfy
+    /// `keep` given their presence in the world. Missing resources are marked
+    /// like "Configuration (missing)".
+    pub(crate) fn names(
+        world: &World,
+        keep: impl Fn(&dyn RegisteredResource, bool) -> bool,
+    ) -> Vec<(String, usize)> {
+        world
+            .resource::<RegisteredResources>()
+            .0
+            .iter()
+            .enumerate()
+            .filter_map(|(index, resource)| {
+                let present = resource.contains(world);
+                keep(resource.as_ref(), present).then(|| {
+                    if present {
+                        (resource.name(), index)
+                    } else {
+                        (format!("{} (missing)", resource.name()), index)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Return completions for [RegisteredResources::names] or `None` if there
+    /// are none.
+    pub(crate) fn completions(
+        world: &World,
+        keep: impl Fn(&dyn RegisteredResource, bool) -> bool,
+    ) -> Option<Trie<u8, usize>> {
+        let names = Self::names(world, keep);
+        (!names.is_empty()).then(|| Trie::from_iter(names))
+    }
+}
+
+/// Serialize a resource's reflected value to RON.
+pub(crate) fn resource_to_ron(
+    world: &World,
+    resource: &dyn RegisteredResource,
+) -> Result<String, String> {
+    let value = resource
+        .reflect(world)
+        .ok_or_else(|| format!("{} is missing.", resource.name()))?;
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    ron::ser::to_string_pretty(
+        &TypedReflectSerializer::new(value.as_partial_reflect(), &type_registry),
+        PrettyConfig::default(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Deserialize a RON value of a resource's type.
+pub(crate) fn resource_from_ron(
+    world: &World,
+    resource: &dyn RegisteredResource,
+    input: &str,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let registration = type_registry
+        .get(resource.resource_type_id())
+        .ok_or_else(|| format!("{} is not registered.", resource.name()))?;
+    ron::Options::default()
+        .from_str_seed(
+            input,
+            TypedReflectDeserializer::new(registration, &type_registry),
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Snapshots of registered resources by index.
+#[derive(Resource, Default)]
+struct ResourceSnapshots(HashMap<usize, Box<dyn PartialReflect>>);
+
+/// How to notify of a registered resource's changes.
+enum Notify {
+    /// Show the resource's name
+    Name,
+    /// Show the changed fields compared to the last value
+    Fields(Option<Box<dyn PartialReflect>>),
+}
+
+/// Registered resources to notify of changes by index.
+#[derive(Resource, Default)]
+struct ResourceNotifications(HashMap<usize, Notify>);
+
+/// Where resource presets are saved.
+#[derive(Resource)]
+struct ResourcePresets {
+    directory: PathBuf,
+}
+
+impl ResourcePresets {
+    /// Return the path of a resource's preset. Names that would leave the
+    /// resource's preset directory, like "../x" or "a/b", are rejected.
+    fn path(&self, resource: &str, preset: &str) -> Result<PathBuf, String> {
+        let mut components = Path::new(preset).components();
+        let single = matches!(
+            (components.next(), components.next()),
+            (Some(Component::Normal(_)), None)
+        );
+        if !single || preset.contains(['/', '\\']) {
+            return Err(format!("Invalid preset name \"{preset}\"."));
+        }
+        Ok(self.directory.join(resource).join(format!("{preset}.ron")))
+    }
+
+    /// Return the names of a resource's saved presets.
+    fn names(&self, resource: &str) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(self.directory.join(resource)) else {
+            return vec![];
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                (path.extension()? == "ron")
+                    .then(|| path.file_stem()?.to_str().map(str::to_string))
+                    .flatten()
+            })
+            .collect();
+        names.sort();
+        names
+    }
+}
+
 impl ActsPluginGroup for ResourceActs {
     fn acts(&self) -> &Acts {
         &self.acts
@@ -51,22 +487,261 @@ impl ActsPluginGroup for ResourceActs {
 
 impl ResourceActs {
     /// Add a resource to the list of resources when prompted.
-    pub fn add<R: Resource + Reflect>(mut self) -> Self {
+    pub fn add<R: Resource + Reflect>(self) -> Self {
+        self.add_registered::<R>(false)
+    }
+
+    /// Add a resource that is shown without allowing edits.
+    pub fn add_read_only<R: Resource + Reflect>(self) -> Self {
+        self.add_registered::<R>(true)
+    }
+
+    /// Add a resource that doesn't implement `Reflect`. Its `{:#?}` output is
+    /// shown without allowing edits.
+    pub fn add_debug<R: Resource + Debug>(mut self) -> Self {
+        self.plugins
+            .add_inspector(pretty_type_name::<R>(), Self::debug_inspector_plugin::<R>);
+        self.resources
+            .push(Box::new(RegisteredDebug::<R>(PhantomData)));
+        self
+    }
+
+    fn add_registered<R: Resource + Reflect>(mut self, read_only: bool) -> Self {
+        self.plugins
+            .add_inspector(pretty_type_name::<R>(), move |index, inspector_plugins| {
+                Self::resource_inspector_plugin::<R>(index, inspector_plugins, read_only)
+            });
+        self.resources.push(Box::new(Registered::<R> {
+            read_only,
+            marker: PhantomData,
+        }));
+        self
+    }
+
+    /// Set the directory resource presets are saved in. The default is
+    /// "presets".
+    pub fn preset_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.preset_directory = directory.into();
+        self
+    }
+
+    /// Add a non-send resource. Its inspector runs on the main thread.
+    pub fn add_non_send<R: Reflect>(mut self) -> Self {
         self.plugins.add_inspector(
             pretty_type_name::<R>(),
-            Self::resource_inspector_plugin::<R>,
+            Self::non_send_inspector_plugin::<R>,
         );
+        self.resources
+            .push(Box::new(RegisteredNonSend::<R>(PhantomData)));
+        self
+    }
+
+    /// Add a non-send resource that doesn't implement `Reflect`. Its `{:#?}`
+    /// output is shown without allowing edits by a system on the main thread.
+    pub fn add_non_send_debug<R: Debug + 'static>(mut self) -> Self {
+        self.plugins.add_inspector(
+            pretty_type_name::<R>(),
+            Self::non_send_debug_inspector_plugin::<R>,
+        );
+        self.resources
+            .push(Box::new(RegisteredNonSendDebug::<R>(PhantomData)));
         self
     }
 
     fn resource_inspector_plugin<R: Resource + Reflect>(
         index: usize,
         inspector_plugins: &mut InspectorPlugins<Self>,
+        read_only: bool,
+    ) {
+        inspector_plugins.add_plugin(move |app: &mut App| {
+            if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+                app.add_plugins(DefaultInspectorConfigPlugin);
+            }
+            let inspector_ui = move |world: &mut World| {
+                reflect_inspector_ui(world, index, read_only, resource_ui::<R>)
+            };
+            app.add_systems(
+                EguiPrimaryContextPass,
+                inspector_ui.run_if(
+                    in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
+                ),
+            );
+        });
+    }
+
+    fn non_send_inspector_plugin<R: Reflect>(
+        index: usize,
+        inspector_plugins: &mut InspectorPlugins<Self>,
+    ) {
+        inspector_plugins.add_plugin(move |app: &mut App| {
+            if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+                app.add_plugins(DefaultInspectorConfigPlugin);
+            }
+            // Exclusive systems run on the main thread, which non-send
+            // resources require.
+            let inspector_ui = move |world: &mut World| {
+                reflect_inspector_ui(world, index, false, non_send_ui::<R>)
+            };
+            app.add_systems(
+                EguiPrimaryContextPass,
+                inspector_ui.run_if(
+                    in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
+                ),
+            );
+        });
+    }
+
+    fn debug_inspector_plugin<R: Resource + Debug>(
+        index: usize,
+        inspector_plugins: &mut InspectorPlugins<Self>,
     ) {
-        inspector_plugins.add_plugin(
-            ResourceInspectorPlugin::<R>::default().run_if(
-                in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
-            ),
+        inspector_plugins.add_plugin(move |app: &mut App| {
+            app.add_systems(
+                EguiPrimaryContextPass,
+                debug_resource_ui::<R>.run_if(
+                    in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
+                ),
+            );
+        });
+    }
+
+    fn non_send_debug_inspector_plugin<R: Debug + 'static>(
+        index: usize,
+        inspector_plugins: &mut InspectorPlugins<Self>,
+    ) {
+        inspector_plugins.add_plugin(move |app: &mut App| {
+            app.add_systems(
+                EguiPrimaryContextPass,
+                debug_non_send_ui::<R>.run_if(
+                    in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
+                ),
+            );
+        });
+    }
+}
+
+fn debug_resource_ui<R: Resource + Debug>(world: &mut World) {
+    let text = world
+        .get_resource::<R>()
+        .map(|resource| format!("{resource:#?}"));
+    debug_inspector_ui(world, pretty_type_name::<R>(), text);
+}
+
+fn debug_non_send_ui<R: Debug + 'static>(world: &mut World) {
+    let text = world
+        .get_non_send_resource::<R>()
+        .map(|resource| format!("{resource:#?}"));
+    debug_inspector_ui(world, pretty_type_name::<R>(), text);
+}
+
+/// Show a resource's `Debug` output in a window or that it's missing.
+fn debug_inspector_ui(world: &mut World, name: String, text: Option<String>) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let text = text.unwrap_or_else(|| format!("{name} is missing."));
+
+    egui::Window::new(name)
+        .default_size((0., 0.))
+        .show(egui_context.get_mut(), |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                ui.monospace(text);
+                ui.allocate_space(ui.available_size());
+            });
+        });
+}
+
+/// Edit a resource's reflected value.
+fn resource_ui<R: Resource + Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    bevy_inspector::ui_for_resource::<R>(world, ui);
+}
+
+/// Edit a non-send resource's reflected value.
+fn non_send_ui<R: Reflect>(world: &mut World, ui: &mut egui::Ui) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let Some(mut resource) = world.get_non_send_resource_mut::<R>() else {
+        ui.label(format!("{} is missing.", pretty_type_name::<R>()));
+        return;
+    };
+    let changed = reflect_inspector::ui_for_value(
+        resource.bypass_change_detection().as_partial_reflect_mut(),
+        ui,
+        &type_registry.read(),
+    );
+    if changed {
+        resource.set_changed();
+    }
+}
+
+/// Show a registered resource's inspector window, editing it with `edit_ui`,
+/// and record its edits.
+fn reflect_inspector_ui(
+    world: &mut World,
+    index: usize,
+    read_only: bool,
+    edit_ui: fn(&mut World, &mut egui::Ui),
+) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let value = |world: &World| {
+        world.resource::<RegisteredResources>().0[index]
+            .reflect(world)
+            .map(|value| utils::clone_value(value.as_partial_reflect()))
+    };
+    let name = world.resource::<RegisteredResources>().0[index].name();
+    if read_only || world.contains_resource::<ReadOnlyInspectors>() {
+        let world: &World = world;
+        let value = world.resource::<RegisteredResources>().0[index].reflect(world);
+        egui::Window::new(name.as_str())
+            .default_size((0., 0.))
+            .show(egui_context.get_mut(), |ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    match value {
+                        Some(value) => readonly_value_ui(world, value.as_partial_reflect(), ui),
+                        None => {
+                            ui.label(format!("{name} is missing."));
+                        }
+                    }
+                    ui.allocate_space(ui.available_size());
+                });
+            });
+        return;
+    }
+    let before = world
+        .contains_resource::<EditHistory>()
+        .then(|| value(world))
+        .flatten();
+
+    egui::Window::new(name.as_str())
+        .default_size((0., 0.))
+        .show(egui_context.get_mut(), |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                edit_ui(world, ui);
+                ui.allocate_space(ui.available_size());
+            });
+        });
+
+    if let Some(before) = before {
+        let Some(after) = value(world) else {
+            return;
+        };
+        EditHistory::record(
+            world,
+            name,
+            EditTarget::Resource(index),
+            before,
+            after.as_ref(),
         );
     }
 }
@@ -75,32 +750,529 @@ impl Default for ResourceActs {
     fn default() -> Self {
         Self {
             plugins: InspectorPlugins::default(),
-            acts: Acts::new([Act::new(inspect_resource)]),
+            resources: vec![],
+            preset_directory: PathBuf::from("presets"),
+            acts: Acts::new([
+                Act::new(inspect_resource),
+                Act::new(insert_resource),
+                Act::new(remove_resource),
+                Act::new(save_resource_preset),
+                Act::new(load_resource_preset),
+                Act::new(snapshot_resource),
+                Act::new(diff_resource),
+                Act::new(save_resource),
+                Act::new(load_resource),
+                Act::new(notify_resource_change),
+            ]),
         }
     }
 }
 
-fn inspect_resource(resources: Res<Inspectors<ResourceActs>>, mut minibuffer: Minibuffer) {
-    if !resources.visible.is_empty() {
-        minibuffer
-            .prompt_map("resource: ", resources.names.clone())
-            .observe(
-                |mut trigger: On<Completed<usize>>,
-                 mut minibuffer: Minibuffer,
-                 mut resources: ResMut<Inspectors<ResourceActs>>| {
-                    match trigger.event_mut().state.take_result().unwrap() {
-                        Ok(index) => {
-                            resources.visible[index] = !resources.visible[index];
-                            minibuffer.clear();
-                        }
-                        Err(e) => {
-                            minibuffer.message(format!("{e}"));
+/// What an act does with the resource it prompts for.
+#[derive(Clone, Copy, PartialEq)]
+enum Access {
+    /// Show or toggle it
+    Show,
+    /// Read its reflected value
+    Reflect,
+    /// Change it
+    Edit,
+}
+
+impl Access {
+    /// Return true if the resource allows this access.
+    fn allows(self, resource: &dyn RegisteredResource) -> bool {
+        match self {
+            Access::Show => true,
+            Access::Reflect => resource.reflected(),
+            Access::Edit => resource.reflected() && !resource.read_only(),
+        }
+    }
+}
+
+/// Prompt for a registered resource whose presence satisfies `keep` and that
+/// allows `access`, then run `next` with its index.
+fn prompt_resource<S, M>(
+    commands: &mut Commands,
+    access: Access,
+    keep: fn(bool) -> bool,
+    none_msg: &'static str,
+    next: S,
+) where
+    S: IntoSystem<In<usize>, (), M> + Copy + Send + Sync + 'static,
+    M: 'static,
+{
+    commands.queue(move |world: &mut World| {
+        if access == Access::Edit && refuse_edit(world) {
+            return;
+        }
+        let Some(names) = RegisteredResources::completions(world, |resource, present| {
+            keep(present) && access.allows(resource)
+        }) else {
+            utils::message(world, none_msg);
+            return;
+        };
+        if let Err(e) = world.run_system_cached_with(
+            move |In(names): In<Trie<u8, usize>>, mut minibuffer: Minibuffer| {
+                minibuffer.prompt_map("resource: ", names).observe(
+                    move |mut trigger: On<Completed<usize>>,
+                          mut commands: Commands,
+                          mut minibuffer: Minibuffer| {
+                        match trigger.event_mut().state.take_result().unwrap() {
+                            Ok(index) => {
+                                commands.run_system_cached_with(next, index);
+                            }
+                            Err(e) => {
+                                minibuffer.message(format!("{e}"));
+                            }
                         }
+                    },
+                );
+            },
+            names,
+        ) {
+            warn!("Unable to prompt for resource: {e}");
+        }
+    });
+}
+
+fn inspect_resource(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Show,
+        |_| true,
+        "No resource inspectors available.",
+        toggle_resource_inspector,
+    );
+}
+
+fn toggle_resource_inspector(
+    In(index): In<usize>,
+    mut resources: ResMut<Inspectors<ResourceActs>>,
+    mut minibuffer: Minibuffer,
+) {
+    resources.visible[index] = !resources.visible[index];
+    minibuffer.clear();
+}
+
+fn insert_resource(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Edit,
+        |present| !present,
+        "No registered resources are missing.",
+        insert_default_resource,
+    );
+}
+
+fn insert_default_resource(In(index): In<usize>, world: &mut World) {
+    let result = world.resource_scope(|world, resources: Mut<RegisteredResources>| {
+        let resource = &resources.0[index];
+        utils::default_value(world, resource.resource_type_id())
+            .and_then(|value| resource.insert(world, value))
+            .map(|_| resource.name())
+    });
+    match result {
+        Ok(name) => utils::message(world, format!("Inserted {name}.")),
+        Err(e) => utils::message(world, e),
+    }
+}
+
+fn remove_resource(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Edit,
+        |present| present,
+        "No registered resources are present.",
+        remove_chosen_resource,
+    );
+}
+
+fn remove_chosen_resource(In(index): In<usize>, world: &mut World) {
+    let name = world.resource_scope(|world, resources: Mut<RegisteredResources>| {
+        let resource = &resources.0[index];
+        resource.remove(world);
+        resource.name()
+    });
+    utils::message(world, format!("Removed {name}."));
+}
+
+fn save_resource_preset(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Reflect,
+        |present| present,
+        "No registered resources are present.",
+        prompt_save_preset,
+    );
+}
+
+fn prompt_save_preset(
+    In(index): In<usize>,
+    resources: Res<RegisteredResources>,
+    presets: Res<ResourcePresets>,
+    mut minibuffer: Minibuffer,
+) {
+    let names = presets.names(&resources.0[index].name());
+    minibuffer.prompt_lookup("preset: ", names).observe(
+        move |mut trigger: On<Submit<String>>,
+              mut commands: Commands,
+              mut minibuffer: Minibuffer| {
+            match trigger.event_mut().take_result() {
+                Ok(preset) => {
+                    commands.run_system_cached_with(save_preset, (index, preset));
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn save_preset(In((index, preset)): In<(usize, String)>, world: &mut World) {
+    let resource = &world.resource::<RegisteredResources>().0[index];
+    let name = resource.name();
+    let result = world
+        .resource::<ResourcePresets>()
+        .path(&name, &preset)
+        .and_then(|path| {
+            let ron = resource_to_ron(world, resource.as_ref())?;
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+            }
+            fs::write(&path, ron).map_err(|e| e.to_string())
+        });
+    match result {
+        Ok(()) => utils::message(world, format!("Saved {name} preset \"{preset}\".")),
+        Err(e) => utils::message(world, e),
+    }
+}
+
+fn load_resource_preset(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Edit,
+        |present| present,
+        "No registered resources are present.",
+        prompt_load_preset,
+    );
+}
+
+fn prompt_load_preset(
+    In(index): In<usize>,
+    resources: Res<RegisteredResources>,
+    presets: Res<ResourcePresets>,
+    mut minibuffer: Minibuffer,
+) {
+    let name = resources.0[index].name();
+    let names = presets.names(&name);
+    if names.is_empty() {
+        minibuffer.message(format!("No presets saved for {name}."));
+        return;
+    }
+    minibuffer.prompt_map("preset: ", names).observe(
+        move |mut trigger: On<Completed<String>>,
+              mut commands: Commands,
+              mut minibuffer: Minibuffer| {
+            match trigger.event_mut().state.take_result().unwrap() {
+                Ok(preset) => {
+                    commands.run_system_cached_with(load_preset, (index, preset));
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn load_preset(In((index, preset)): In<(usize, String)>, world: &mut World) {
+    let result = world.resource_scope(|world, resources: Mut<RegisteredResources>| {
+        let resource = resources.0[index].as_ref();
+        let path = world
+            .resource::<ResourcePresets>()
+            .path(&resource.name(), &preset)?;
+        let input = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let value = resource_from_ron(world, resource, &input)?;
+        resource.apply(world, value.as_ref())?;
+        Ok::<_, String>(resource.name())
+    });
+    match result {
+        Ok(name) => utils::message(world, format!("Loaded {name} preset \"{preset}\".")),
+        Err(e) => utils::message(world, e),
+    }
+}
+
+fn snapshot_resource(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Reflect,
+        |present| present,
+        "No registered resources are present.",
+        take_snapshot,
+    );
+}
+
+fn take_snapshot(In(index): In<usize>, world: &mut World) {
+    let resource = &world.resource::<RegisteredResources>().0[index];
+    let name = resource.name();
+    let Some(value) = resource.reflect(world) else {
+        return;
+    };
+    let snapshot = utils::clone_value(value.as_partial_reflect());
+    world
+        .resource_mut::<ResourceSnapshots>()
+        .0
+        .insert(index, snapshot);
+    utils::message(world, format!("Took a snapshot of {name}."));
+}
+
+fn diff_resource(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Reflect,
+        |present| present,
+        "No registered resources are present.",
+        show_diff,
+    );
+}
+
+fn show_diff(In(index): In<usize>, world: &mut World) {
+    let resource = &world.resource::<RegisteredResources>().0[index];
+    let name = resource.name();
+    let msg = match (
+        world.resource::<ResourceSnapshots>().0.get(&index),
+        resource.reflect(world),
+    ) {
+        (None, _) => format!("No snapshot of {name}; use 'snapshot_resource' first."),
+        (Some(_), None) => format!("{name} is missing."),
+        (Some(snapshot), Some(value)) => {
+            let changes = utils::diff_fields(snapshot.as_ref(), value.as_partial_reflect());
+            if changes.is_empty() {
+                format!("No changes to {name} since its snapshot.")
+            } else {
+                let mut msg = format!("{name} changed since its snapshot:");
+                for change in changes {
+                    msg.push_str(&format!("\n{change}"));
+                }
+                msg
+            }
+        }
+    };
+    utils::message(world, msg);
+}
+
+fn save_resource(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Reflect,
+        |present| present,
+        "No registered resources are present.",
+        prompt_save_path,
+    );
+}
+
+fn prompt_save_path(In(index): In<usize>, mut minibuffer: Minibuffer) {
+    minibuffer.prompt_lookup("file: ", RonPaths).observe(
+        move |mut trigger: On<Submit<String>>,
+              mut commands: Commands,
+              mut minibuffer: Minibuffer| {
+            match trigger.event_mut().take_result() {
+                Ok(path) => {
+                    commands.run_system_cached_with(write_resource, (index, PathBuf::from(path)));
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn write_resource(In((index, path)): In<(usize, PathBuf)>, world: &mut World) {
+    let resource = &world.resource::<RegisteredResources>().0[index];
+    let name = resource.name();
+    let result = resource
+        .reflect(world)
+        .ok_or_else(|| format!("{name} is missing."))
+        .and_then(|value| {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            ron::ser::to_string_pretty(
+                &ReflectSerializer::new(value.as_partial_reflect(), &type_registry),
+                PrettyConfig::default(),
+            )
+            .map_err(|e| e.to_string())
+        })
+        .and_then(|ron| fs::write(&path, ron).map_err(|e| format!("{}: {e}", path.display())));
+    match result {
+        Ok(()) => utils::message(world, format!("Saved {name} to {}.", path.display())),
+        Err(e) => utils::message(world, e),
+    }
+}
+
+fn load_resource(read_only: Option<Res<ReadOnlyInspectors>>, mut minibuffer: Minibuffer) {
+    if read_only.is_some() {
+        minibuffer.message(READ_ONLY_MSG);
+        return;
+    }
+    minibuffer.prompt_lookup("file: ", RonPaths).observe(
+        |mut trigger: On<Submit<String>>, mut commands: Commands, mut minibuffer: Minibuffer| {
+            match trigger.event_mut().take_result() {
+                Ok(path) => {
+                    commands.run_system_cached_with(read_resource, PathBuf::from(path));
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn read_resource(In(path): In<PathBuf>, world: &mut World) {
+    let result = world.resource_scope(|world, resources: Mut<RegisteredResources>| {
+        let input = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let value = {
+            let type_registry = world.resource::<AppTypeRegistry>().read();
+            ron::Options::default()
+                .from_str_seed(&input, ReflectDeserializer::new(&type_registry))
+                .map_err(|e| e.to_string())?
+        };
+        let type_id = value.get_represented_type_info().map(|info| info.type_id());
+        let resource = resources
+            .0
+            .iter()
+            .find(|resource| Some(resource.resource_type_id()) == type_id)
+            .ok_or_else(|| format!("{} is not a registered resource.", path.display()))?;
+        if resource.read_only() {
+            return Err(format!("{} is read-only.", resource.name()));
+        }
+        resource.apply(world, value.as_ref())?;
+        Ok::<_, String>(resource.name())
+    });
+    match result {
+        Ok(name) => utils::message(world, format!("Loaded {name} from {}.", path.display())),
+        Err(e) => utils::message(world, e),
+    }
+}
+
+fn notify_resource_change(mut commands: Commands) {
+    prompt_resource(
+        &mut commands,
+        Access::Show,
+        |_| true,
+        "No resources registered.",
+        prompt_notify,
+    );
+}
+
+fn prompt_notify(In(index): In<usize>, mut minibuffer: Minibuffer) {
+    minibuffer
+        .prompt_lookup("notify: ", vec!["off", "name", "fields"])
+        .observe(
+            move |mut trigger: On<Submit<String>>,
+                  mut commands: Commands,
+                  mut minibuffer: Minibuffer| {
+                match trigger.event_mut().take_result() {
+                    Ok(choice) => {
+                        commands.run_system_cached_with(set_notify, (index, choice));
+                    }
+                    Err(e) => {
+                        minibuffer.message(format!("{e}"));
                     }
-                },
+                }
+            },
+        );
+}
+
+fn set_notify(In((index, choice)): In<(usize, String)>, world: &mut World) {
+    let resource = &world.resource::<RegisteredResources>().0[index];
+    let name = resource.name();
+    let notify = match choice.as_str() {
+        "off" => None,
+        "name" => Some(Notify::Name),
+        "fields" if !resource.reflected() => {
+            utils::message(world, format!("{name} is not reflected; try name."));
+            return;
+        }
+        "fields" => Some(Notify::Fields(
+            resource
+                .reflect(world)
+                .map(|value| utils::clone_value(value.as_partial_reflect())),
+        )),
+        _ => {
+            utils::message(
+                world,
+                format!("Expected off, name, or fields, not {choice:?}."),
             );
-    } else {
-        minibuffer.message("No resource inspectors available.");
+            return;
+        }
+    };
+    let mut notifications = world.resource_mut::<ResourceNotifications>();
+    let msg = match notify {
+        Some(notify) => {
+            notifications.0.insert(index, notify);
+            format!("Notifying when {name} changes.")
+        }
+        None => {
+            notifications.0.remove(&index);
+            format!("Not notifying when {name} changes.")
+        }
+    };
+    utils::message(world, msg);
+}
+
+/// How many notification lines to hold while a prompt is open
+const MAX_PENDING_LINES: usize = 20;
+
+/// Show a message when a resource with notifications on changes. A message
+/// would replace an open prompt, so the lines wait until it closes.
+fn notify_changed_resources(world: &mut World, mut lines: Local<Vec<String>>) {
+    if world.resource::<ResourceNotifications>().0.is_empty() && lines.is_empty() {
+        return;
+    }
+    world.resource_scope(|world, mut notifications: Mut<ResourceNotifications>| {
+        let resources = world.resource::<RegisteredResources>();
+        for (index, notify) in notifications.0.iter_mut() {
+            let resource = &resources.0[*index];
+            if !resource.is_changed(world) {
+                continue;
+            }
+            match notify {
+                Notify::Name => lines.push(format!("{} changed.", resource.name())),
+                Notify::Fields(last) => {
+                    let Some(value) = resource.reflect(world) else {
+                        continue;
+                    };
+                    let value = value.as_partial_reflect();
+                    let changes = match last {
+                        Some(last) => utils::diff_fields(last.as_ref(), value),
+                        None => vec![],
+                    };
+                    if last.is_none() {
+                        lines.push(format!("{} added.", resource.name()));
+                    } else if !changes.is_empty() {
+                        lines.push(format!("{} changed:", resource.name()));
+                        lines.extend(changes.iter().map(|change| change.to_string()));
+                    }
+                    *last = Some(utils::clone_value(value));
+                }
+            }
+        }
+    });
+    let prompting = world
+        .query_filtered::<(), With<TextField>>()
+        .iter(world)
+        .next()
+        .is_some();
+    if prompting {
+        // Keep only the latest lines while waiting.
+        let excess = lines.len().saturating_sub(MAX_PENDING_LINES);
+        lines.drain(..excess);
+    } else if !lines.is_empty() {
+        utils::message(world, lines.join("\n"));
+        lines.clear();
     }
 }
 
@@ -109,6 +1281,39 @@ impl PluginGroup for ResourceActs {
         self.warn_on_unused_acts();
         self.plugins
             .warn_on_empty("No resources registered with `ResourceActs`; consider adding some.");
-        self.plugins.build()
+        let mut plugins = self.plugins;
+        let resources = Mutex::new(Some(self.resources));
+        let directory = self.preset_directory;
+        plugins.add_plugin(move |app: &mut App| {
+            if let Some(resources) = resources.lock().unwrap().take() {
+                app.insert_resource(RegisteredResources(resources));
+            }
+            app.insert_resource(ResourcePresets {
+                directory: directory.clone(),
+            })
+            .init_resource::<ResourceSnapshots>()
+            .init_resource::<ResourceNotifications>()
+            .add_systems(Last, notify_changed_resources);
+        });
+        plugins.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preset_path() {
+        let presets = ResourcePresets {
+            directory: PathBuf::from("presets"),
+        };
+        assert_eq!(
+            presets.path("Gravity", "moon"),
+            Ok(PathBuf::from("presets/Gravity/moon.ron"))
+        );
+        for preset in ["../x", "a/b", ".", ""] {
+            assert!(presets.path("Gravity", preset).is_err(), "{preset:?}");
+        }
     }
 }
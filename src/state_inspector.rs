@@ -1,17 +1,28 @@
-use crate::{utils::pretty_type_name, InspectorPlugins, Inspectors};
-use bevy_app::{PluginGroup, PluginGroupBuilder};
+use crate::{
+    read_only::readonly_value_ui, utils::pretty_type_name, EditHistory, EditTarget,
+    InspectorPlugins, Inspectors, ReadOnlyInspectors,
+};
+use bevy_app::{App, PluginGroup, PluginGroupBuilder};
 use bevy_ecs::{
-    prelude::{On, Res, ResMut},
+    prelude::{IntoScheduleConfigs, On, Res, ResMut, With, World},
     schedule::SystemCondition,
 };
-use bevy_inspector_egui::quick::StateInspectorPlugin;
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    bevy_inspector, egui, DefaultInspectorConfigPlugin,
+};
 use bevy_minibuffer::{prelude::*, prompt::PromptState};
-use bevy_reflect::Reflect;
-use bevy_state::{prelude::in_state, state::FreelyMutableState};
+use bevy_reflect::{PartialReflect, Reflect};
+use bevy_state::{
+    prelude::in_state,
+    state::{FreelyMutableState, NextState, State},
+};
+use std::any::TypeId;
 
 /// ## Adds the 'inspect_state' act
 ///
-/// This act toggles the visibility of registered state inspectors.
+/// This act toggles the visibility of registered state inspectors. States
+/// added with [StateActs::add_read_only] are shown without allowing edits.
 ///
 /// ## Usage
 ///
@@ -64,18 +75,111 @@ impl StateActs {
     /// Add a state to the list of inspectors when prompted.
     pub fn add<S: FreelyMutableState + Reflect>(mut self) -> Self {
         self.plugins
-            .add_inspector(pretty_type_name::<S>(), Self::add_plugin::<S>);
+            .add_inspector(pretty_type_name::<S>(), |index, inspector_plugins| {
+                Self::add_plugin::<S>(index, inspector_plugins, false)
+            });
+        self
+    }
+
+    /// Add a state that is shown without allowing edits.
+    pub fn add_read_only<S: FreelyMutableState + Reflect>(mut self) -> Self {
+        self.plugins
+            .add_inspector(pretty_type_name::<S>(), |index, inspector_plugins| {
+                Self::add_plugin::<S>(index, inspector_plugins, true)
+            });
         self
     }
 
     fn add_plugin<A: FreelyMutableState + Reflect>(
         index: usize,
         inspector_plugins: &mut InspectorPlugins<Self>,
+        read_only: bool,
     ) {
-        inspector_plugins.add_plugin(
-            StateInspectorPlugin::<A>::default().run_if(
-                in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
-            ),
+        inspector_plugins.add_plugin(move |app: &mut App| {
+            if !app.is_plugin_added::<DefaultInspectorConfigPlugin>() {
+                app.add_plugins(DefaultInspectorConfigPlugin);
+            }
+            app.add_systems(
+                EguiPrimaryContextPass,
+                (move |world: &mut World| state_inspector_ui::<A>(world, read_only)).run_if(
+                    in_state(PromptState::Visible).and(InspectorPlugins::<Self>::visible(index)),
+                ),
+            );
+        });
+    }
+}
+
+/// Return the state's pending next value or else its current value.
+fn state_value<S: FreelyMutableState>(world: &World) -> Option<S> {
+    match world.get_resource::<NextState<S>>()? {
+        NextState::Pending(next) => Some(next.clone()),
+        NextState::Unchanged => world
+            .get_resource::<State<S>>()
+            .map(|state| state.get().clone()),
+    }
+}
+
+/// Set the state's next value from a reflected value.
+fn write_state<S: FreelyMutableState + Reflect>(
+    world: &mut World,
+    value: &dyn PartialReflect,
+) -> Result<(), String> {
+    let mut next = world
+        .get_resource::<State<S>>()
+        .ok_or_else(|| format!("No state {}.", pretty_type_name::<S>()))?
+        .get()
+        .clone();
+    next.try_apply(value).map_err(|e| e.to_string())?;
+    world.resource_mut::<NextState<S>>().set(next);
+    Ok(())
+}
+
+/// Show a state's inspector window and record its edits.
+fn state_inspector_ui<S: FreelyMutableState + Reflect>(world: &mut World, read_only: bool) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    if read_only || world.contains_resource::<ReadOnlyInspectors>() {
+        egui::Window::new(std::any::type_name::<S>())
+            .resizable(false)
+            .title_bar(false)
+            .show(egui_context.get_mut(), |ui| {
+                egui::ScrollArea::both().show(ui, |ui| {
+                    ui.heading(pretty_type_name::<S>());
+                    if let Some(state) = world.get_resource::<State<S>>() {
+                        readonly_value_ui(world, state.get(), ui);
+                    }
+                });
+            });
+        return;
+    }
+    let before = world
+        .contains_resource::<EditHistory>()
+        .then(|| state_value::<S>(world))
+        .flatten();
+
+    egui::Window::new(std::any::type_name::<S>())
+        .resizable(false)
+        .title_bar(false)
+        .show(egui_context.get_mut(), |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                ui.heading(pretty_type_name::<S>());
+                bevy_inspector::ui_for_state::<S>(world, ui);
+            });
+        });
+
+    if let (Some(before), Some(after)) = (before, state_value::<S>(world)) {
+        EditHistory::record(
+            world,
+            pretty_type_name::<S>(),
+            EditTarget::State(TypeId::of::<S>(), write_state::<S>),
+            Box::new(before),
+            &after,
         );
     }
 }
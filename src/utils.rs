@@ -1,3 +1,600 @@
+use bevy_ecs::{
+    archetype::Archetypes,
+    component::Components,
+    entity::{Entities, Entity},
+    name::Name,
+    prelude::{In, World},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectFromWorld},
+};
+use bevy_log::warn;
+use bevy_minibuffer::prelude::*;
+use bevy_reflect::{
+    serde::TypedReflectDeserializer, std_traits::ReflectDefault, PartialReflect, Reflect,
+    ReflectPath, ReflectRef, TypeRegistration, TypeRegistry,
+};
+use std::{any::TypeId, cmp::Ordering, collections::HashSet, fmt, path::Path};
+use trie_rs::map::Trie;
+
 pub fn pretty_type_name<T>() -> String {
     format!("{:?}", disqualified::ShortName::of::<T>())
 }
+
+/// Return a label for an entity like "Camera (3v0)" or "3v0" if it has no
+/// name.
+pub fn entity_label(entity: Entity, name: Option<&Name>) -> String {
+    match name {
+        Some(name) => format!("{name} ({entity})"),
+        None => format!("{entity}"),
+    }
+}
+
+/// Return a trie of entity labels or `None` if there are no entities.
+pub fn entity_trie<'a>(
+    entities: impl Iterator<Item = (Entity, Option<&'a Name>)>,
+) -> Option<Trie<u8, Entity>> {
+    let labels: Vec<(String, Entity)> = entities
+        .map(|(entity, name)| (entity_label(entity, name), entity))
+        .collect();
+    (!labels.is_empty()).then(|| Trie::from_iter(labels))
+}
+
+/// Return the reflected components an entity has as `(short name, type id)`
+/// pairs.
+pub fn reflected_components(
+    entity: Entity,
+    entities: &Entities,
+    archetypes: &Archetypes,
+    components: &Components,
+    type_registry: &TypeRegistry,
+) -> Vec<(String, TypeId)> {
+    let Some(location) = entities.get(entity) else {
+        return vec![];
+    };
+    archetypes[location.archetype_id]
+        .components()
+        .iter()
+        .filter_map(|id| components.get_info(*id)?.type_id())
+        .filter_map(|type_id| {
+            let registration = type_registry.get(type_id)?;
+            registration.data::<ReflectComponent>().map(|_| {
+                (
+                    registration
+                        .type_info()
+                        .type_path_table()
+                        .short_path()
+                        .to_string(),
+                    type_id,
+                )
+            })
+        })
+        .collect()
+}
+
+/// Look up a registered type by its full or short type path.
+pub fn registration<'a>(
+    type_registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Option<&'a TypeRegistration> {
+    type_registry
+        .get_with_type_path(type_path)
+        .or_else(|| type_registry.get_with_short_type_path(type_path))
+}
+
+/// Split a field target like "Transform.translation.y" into its type
+/// "Transform" and its reflect path ".translation.y".
+pub fn split_field_target(target: &str) -> (&str, &str) {
+    match target.find('.') {
+        Some(index) => target.split_at(index),
+        None => (target, ""),
+    }
+}
+
+/// Return the reflect paths of a value's fields like ".translation.y" to a
+/// limited depth.
+pub fn field_paths(value: &dyn PartialReflect) -> Vec<String> {
+    let mut paths = vec![];
+    push_field_paths(value, String::new(), 4, &mut paths);
+    paths
+}
+
+fn push_field_paths(
+    value: &dyn PartialReflect,
+    prefix: String,
+    depth: usize,
+    paths: &mut Vec<String>,
+) {
+    if depth == 0 {
+        return;
+    }
+    let fields: Vec<(String, &dyn PartialReflect)> = match value.reflect_ref() {
+        ReflectRef::Struct(value) => (0..value.field_len())
+            .filter_map(|i| {
+                Some((
+                    format!("{prefix}.{}", value.name_at(i)?),
+                    value.field_at(i)?,
+                ))
+            })
+            .collect(),
+        ReflectRef::TupleStruct(value) => (0..value.field_len())
+            .filter_map(|i| Some((format!("{prefix}.{i}"), value.field(i)?)))
+            .collect(),
+        _ => vec![],
+    };
+    for (path, field) in fields {
+        paths.push(path.clone());
+        push_field_paths(field, path, depth - 1, paths);
+    }
+}
+
+/// Return the field targets like "Transform.translation.y" of the reflected
+/// components on the given entities.
+pub fn component_field_targets(world: &World, entities: &[Entity]) -> Vec<String> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let mut seen = HashSet::new();
+    let mut targets = vec![];
+    for entity in entities {
+        for (name, type_id) in reflected_components(
+            *entity,
+            world.entities(),
+            world.archetypes(),
+            world.components(),
+            &type_registry,
+        ) {
+            if !seen.insert(type_id) {
+                continue;
+            }
+            let Some(value) = type_registry
+                .get_type_data::<ReflectComponent>(type_id)
+                .and_then(|reflect_component| reflect_component.reflect(world.entity(*entity)))
+            else {
+                continue;
+            };
+            targets.extend(
+                field_paths(value.as_partial_reflect())
+                    .into_iter()
+                    .map(|path| format!("{name}{path}")),
+            );
+            targets.push(name);
+        }
+    }
+    targets.sort();
+    targets
+}
+
+/// Deserialize a RON value for the type of the field at `path` in `value`.
+pub fn parse_field_value(
+    value: &dyn PartialReflect,
+    path: &str,
+    input: &str,
+    type_registry: &TypeRegistry,
+) -> Result<Box<dyn PartialReflect>, String> {
+    let field = path.reflect_element(value).map_err(|e| e.to_string())?;
+    let registration = field
+        .get_represented_type_info()
+        .and_then(|type_info| type_registry.get(type_info.type_id()))
+        .ok_or_else(|| format!("No registered type for {path}."))?;
+    ron::Options::default()
+        .from_str_seed(
+            input,
+            TypedReflectDeserializer::new(registration, type_registry),
+        )
+        .map_err(|e| e.to_string())
+}
+
+/// Return a numeric value as an `f64`.
+pub fn as_f64(value: &dyn PartialReflect) -> Option<f64> {
+    let value = value.try_as_reflect()?.as_any();
+    macro_rules! downcast {
+        ($($t:ty),*) => {
+            $(if let Some(x) = value.downcast_ref::<$t>() {
+                return Some(*x as f64);
+            })*
+        };
+    }
+    downcast!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    None
+}
+
+/// A reflected value for sorting: numbers compare numerically and everything
+/// else by its debug representation. Numbers sort before text and follow
+/// [f64::total_cmp], so NaN has a place too.
+pub enum SortKey {
+    Number(f64),
+    Text(String),
+}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortKey {}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (SortKey::Number(a), SortKey::Number(b)) => a.total_cmp(b),
+            (SortKey::Number(_), SortKey::Text(_)) => Ordering::Less,
+            (SortKey::Text(_), SortKey::Number(_)) => Ordering::Greater,
+            (SortKey::Text(a), SortKey::Text(b)) => a.cmp(b),
+        }
+    }
+}
+
+/// Return the key to sort a reflected value by.
+pub fn sort_key(value: &dyn PartialReflect) -> SortKey {
+    match as_f64(value) {
+        Some(x) => SortKey::Number(x),
+        None => SortKey::Text(format!("{value:?}")),
+    }
+}
+
+/// Show a message in the minibuffer from an exclusive context.
+pub fn message(world: &mut World, msg: impl Into<String>) {
+    if let Err(e) = world.run_system_cached_with(
+        |In(msg): In<String>, mut minibuffer: Minibuffer| minibuffer.message(msg),
+        msg.into(),
+    ) {
+        warn!("Unable to message minibuffer: {e}");
+    }
+}
+
+/// Build the default value of a registered type with its `ReflectDefault` or
+/// `ReflectFromWorld` type data.
+pub fn default_value(world: &mut World, type_id: TypeId) -> Result<Box<dyn Reflect>, String> {
+    let (default, from_world) = {
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let Some(registration) = type_registry.get(type_id) else {
+            return Err("Type is not registered.".into());
+        };
+        (
+            registration.data::<ReflectDefault>().cloned(),
+            registration.data::<ReflectFromWorld>().cloned(),
+        )
+    };
+    if let Some(default) = default {
+        Ok(default.default())
+    } else if let Some(from_world) = from_world {
+        Ok(from_world.from_world(world))
+    } else {
+        Err("Type does not reflect `Default` or `FromWorld`.".into())
+    }
+}
+
+/// Clone a reflected value, falling back to a dynamic representation.
+pub fn clone_value(value: &dyn PartialReflect) -> Box<dyn PartialReflect> {
+    value
+        .reflect_clone()
+        .map(|value| value.into_partial_reflect())
+        .unwrap_or_else(|_| value.to_dynamic())
+}
+
+/// Return a clone of an entity's reflected component.
+pub fn component_value(
+    world: &World,
+    entity: Entity,
+    type_id: TypeId,
+) -> Option<Box<dyn PartialReflect>> {
+    let reflect_component = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_type_data::<ReflectComponent>(type_id)
+        .cloned()?;
+    let component = reflect_component.reflect(world.get_entity(entity).ok()?)?;
+    Some(clone_value(component.as_partial_reflect()))
+}
+
+/// Apply a reflected value to an entity's component in place.
+pub fn apply_component(
+    world: &mut World,
+    entity: Entity,
+    type_id: TypeId,
+    value: &dyn PartialReflect,
+) -> Result<(), String> {
+    let reflect_component = world
+        .resource::<AppTypeRegistry>()
+        .read()
+        .get_type_data::<ReflectComponent>(type_id)
+        .cloned()
+        .ok_or("Type is not a reflected component.")?;
+    let entity_mut = world
+        .get_entity_mut(entity)
+        .map_err(|_| format!("No entity {entity}."))?;
+    let mut component = reflect_component
+        .reflect_mut(entity_mut)
+        .ok_or_else(|| format!("{entity} no longer has that component."))?;
+    component.try_apply(value).map_err(|e| e.to_string())
+}
+
+/// A field that differs between two reflected values.
+pub struct FieldChange {
+    /// The field's path like ".translation.y" or empty for the whole value
+    pub path: String,
+    pub old: String,
+    pub new: String,
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{} → {}", self.old, self.new)
+        } else {
+            write!(f, "{}: {} → {}", self.path, self.old, self.new)
+        }
+    }
+}
+
+/// Return the leaf fields that differ between `old` and `new`.
+pub fn diff_fields(old: &dyn PartialReflect, new: &dyn PartialReflect) -> Vec<FieldChange> {
+    let mut changes = vec![];
+    push_field_changes(old, new, String::new(), &mut changes);
+    changes
+}
+
+/// A field's path with its old and new values
+type FieldPair<'a> = (String, &'a dyn PartialReflect, &'a dyn PartialReflect);
+
+fn push_field_changes(
+    old: &dyn PartialReflect,
+    new: &dyn PartialReflect,
+    path: String,
+    changes: &mut Vec<FieldChange>,
+) {
+    if old.reflect_partial_eq(new) == Some(true) {
+        return;
+    }
+    let fields: Option<Vec<FieldPair>> = match (old.reflect_ref(), new.reflect_ref()) {
+        (ReflectRef::Struct(old), ReflectRef::Struct(new)) => Some(
+            (0..new.field_len())
+                .filter_map(|i| {
+                    let name = new.name_at(i)?;
+                    Some((format!("{path}.{name}"), old.field(name)?, new.field_at(i)?))
+                })
+                .collect(),
+        ),
+        (ReflectRef::TupleStruct(old), ReflectRef::TupleStruct(new))
+            if old.field_len() == new.field_len() =>
+        {
+            Some(
+                (0..new.field_len())
+                    .filter_map(|i| Some((format!("{path}.{i}"), old.field(i)?, new.field(i)?)))
+                    .collect(),
+            )
+        }
+        (ReflectRef::Tuple(old), ReflectRef::Tuple(new)) if old.field_len() == new.field_len() => {
+            Some(
+                (0..new.field_len())
+                    .filter_map(|i| Some((format!("{path}.{i}"), old.field(i)?, new.field(i)?)))
+                    .collect(),
+            )
+        }
+        (ReflectRef::List(old), ReflectRef::List(new)) if old.len() == new.len() => Some(
+            (0..new.len())
+                .filter_map(|i| Some((format!("{path}[{i}]"), old.get(i)?, new.get(i)?)))
+                .collect(),
+        ),
+        (ReflectRef::Array(old), ReflectRef::Array(new)) if old.len() == new.len() => Some(
+            (0..new.len())
+                .filter_map(|i| Some((format!("{path}[{i}]"), old.get(i)?, new.get(i)?)))
+                .collect(),
+        ),
+        _ => None,
+    };
+    match fields {
+        Some(fields) => {
+            for (path, old, new) in fields {
+                push_field_changes(old, new, path, changes);
+            }
+        }
+        None => {
+            let (old, new) = (format!("{old:?}"), format!("{new:?}"));
+            if old != new {
+                changes.push(FieldChange { path, old, new });
+            }
+        }
+    }
+}
+
+/// Return the longest prefix the `strings` share.
+pub(crate) fn common_prefix<'a>(mut strings: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    let first = strings.next()?;
+    Some(strings.fold(first, |prefix, string| {
+        let len = prefix
+            .char_indices()
+            .zip(string.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((index, a), _)| index + a.len_utf8());
+        &prefix[..len]
+    }))
+}
+
+/// Completes RON file paths one directory at a time like a shell does.
+///
+/// The input is split at its last '/'; the entries of that directory, or the
+/// current directory if there is none, that start with the rest are offered.
+/// Directories complete with a trailing '/' and files only if they end in
+/// ".ron". Hidden entries are skipped unless the rest starts with '.'.
+pub(crate) struct RonPaths;
+
+impl RonPaths {
+    /// Split the input into its directory, including the last '/', and the
+    /// name typed so far.
+    fn split(input: &str) -> (&str, &str) {
+        input.split_at(input.rfind('/').map_or(0, |index| index + 1))
+    }
+
+    /// Return the names in the input's directory that complete it.
+    fn matches(input: &str) -> Vec<String> {
+        let (dir, prefix) = Self::split(input);
+        let Ok(entries) = std::fs::read_dir(if dir.is_empty() { "." } else { dir }) else {
+            return vec![];
+        };
+        let mut names: Vec<String> = entries
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.'))
+                {
+                    return None;
+                }
+                if entry.path().is_dir() {
+                    Some(format!("{name}/"))
+                } else {
+                    name.ends_with(".ron").then_some(name)
+                }
+            })
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+impl Lookup for RonPaths {
+    fn lookup(&self, input: &str) -> Result<(), LookupError> {
+        let (dir, _) = Self::split(input);
+        let matches = Self::matches(input);
+        if Path::new(input).is_file() && matches.iter().any(|name| format!("{dir}{name}") == input)
+        {
+            return Ok(());
+        }
+        match matches.as_slice() {
+            [] => Err(LookupError::NoMatch),
+            [name] => Err(LookupError::OneMatch(format!("{dir}{name}"))),
+            _ => Err(LookupError::ManyMatches),
+        }
+    }
+
+    fn longest_prefix(&self, input: &str) -> Option<String> {
+        let (dir, _) = Self::split(input);
+        let matches = Self::matches(input);
+        common_prefix(matches.iter().map(String::as_str)).map(|prefix| format!("{dir}{prefix}"))
+    }
+
+    fn all_lookups(&self, input: &str) -> Vec<String> {
+        Self::matches(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect, Clone)]
+    struct Inner {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Reflect, Clone)]
+    struct Outer {
+        inner: Inner,
+        items: Vec<u32>,
+    }
+
+    fn outer() -> Outer {
+        Outer {
+            inner: Inner { x: 0.0, y: 1.0 },
+            items: vec![1, 2, 3],
+        }
+    }
+
+    fn paths(changes: &[FieldChange]) -> Vec<&str> {
+        changes.iter().map(|change| change.path.as_str()).collect()
+    }
+
+    #[test]
+    fn diff_equal() {
+        assert!(diff_fields(&outer(), &outer()).is_empty());
+    }
+
+    #[test]
+    fn diff_nested_struct() {
+        let mut new = outer();
+        new.inner.y = 2.0;
+        let changes = diff_fields(&outer(), &new);
+        assert_eq!(paths(&changes), [".inner.y"]);
+        assert_eq!(changes[0].old, "1.0");
+        assert_eq!(changes[0].new, "2.0");
+    }
+
+    #[test]
+    fn diff_list_element() {
+        let mut new = outer();
+        new.items[1] = 5;
+        let changes = diff_fields(&outer(), &new);
+        assert_eq!(paths(&changes), [".items[1]"]);
+        assert_eq!(changes[0].old, "2");
+        assert_eq!(changes[0].new, "5");
+    }
+
+    #[test]
+    fn diff_list_unequal_length() {
+        let mut new = outer();
+        new.items.push(4);
+        new.inner.x = 3.0;
+        let changes = diff_fields(&outer(), &new);
+        // A list whose length changed is reported whole.
+        assert_eq!(paths(&changes), [".inner.x", ".items"]);
+    }
+
+    #[test]
+    fn diff_unequal_shapes() {
+        let changes = diff_fields(&(1u8, 2u8), &(1u8, 2u8, 3u8));
+        assert_eq!(paths(&changes), [""]);
+        let changes = diff_fields(&1u32, &String::from("1"));
+        assert_eq!(paths(&changes), [""]);
+    }
+
+    #[test]
+    fn split_targets() {
+        assert_eq!(
+            split_field_target("Transform.translation.y"),
+            ("Transform", ".translation.y")
+        );
+        assert_eq!(split_field_target("Name"), ("Name", ""));
+        assert_eq!(
+            split_field_target("bevy_transform::components::Transform.scale"),
+            ("bevy_transform::components::Transform", ".scale")
+        );
+    }
+
+    #[test]
+    fn ron_paths() {
+        let root = std::env::temp_dir().join(format!("ron_paths_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("presets/.hidden")).unwrap();
+        for file in ["presets/easy.ron", "presets/hard.ron", "presets/notes.txt"] {
+            std::fs::write(root.join(file), "()").unwrap();
+        }
+        let dir = format!("{}/", root.display());
+        assert!(matches!(
+            RonPaths.lookup(&format!("{dir}pre")),
+            Err(LookupError::OneMatch(s)) if s == format!("{dir}presets/")
+        ));
+        assert_eq!(
+            RonPaths.all_lookups(&format!("{dir}presets/")),
+            ["easy.ron", "hard.ron"]
+        );
+        assert_eq!(
+            RonPaths.all_lookups(&format!("{dir}presets/.")),
+            [".hidden/"]
+        );
+        assert!(RonPaths.lookup(&format!("{dir}presets/easy.ron")).is_ok());
+        assert!(matches!(
+            RonPaths.lookup(&format!("{dir}presets/x")),
+            Err(LookupError::NoMatch)
+        ));
+        assert_eq!(
+            common_prefix(["easy.ron", "ease.ron"].into_iter()),
+            Some("eas")
+        );
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+use crate::field_target::{prompt_field_target, FieldTarget};
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::{
+    Commands, In, IntoScheduleConfigs, On, Res, ResMut, Resource, With, World,
+};
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    egui,
+};
+use bevy_minibuffer::prelude::*;
+use trie_rs::map::Trie;
+
+/// ## Adds the 'watch' and 'unwatch' acts
+///
+/// The 'watch' act prompts for a field of a resource registered with
+/// [ResourceActs](crate::ResourceActs) like "Configuration.option" or an entity
+/// and one of its components' fields like "Transform.translation.y". The field
+/// is pinned to a compact list in the corner of the screen that refreshes every
+/// frame, whether or not the minibuffer is visible.
+///
+/// The 'unwatch' act removes a field from the list.
+///
+/// ## Usage
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_minibuffer::prelude::*;
+/// use bevy_minibuffer_inspector as inspector;
+/// fn plugin(app: &mut App) {
+///     app
+///         .add_plugins(MinibufferPlugins)
+///         .add_acts((
+///             BasicActs::default(),
+///             inspector::WatchActs::default(),
+///         ));
+/// }
+/// ```
+pub struct WatchActs {
+    acts: Acts,
+}
+
+impl ActsPlugin for WatchActs {
+    fn acts(&self) -> &Acts {
+        &self.acts
+    }
+    fn acts_mut(&mut self) -> &mut Acts {
+        &mut self.acts
+    }
+}
+
+impl Default for WatchActs {
+    fn default() -> Self {
+        Self {
+            acts: Acts::new([Act::new(watch), Act::new(unwatch)]),
+        }
+    }
+}
+
+impl Plugin for WatchActs {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Watches>().add_systems(
+            EguiPrimaryContextPass,
+            watch_ui.run_if(|watches: Res<Watches>| !watches.0.is_empty()),
+        );
+        self.warn_on_unused_acts();
+    }
+}
+
+/// The watched fields.
+#[derive(Resource, Default)]
+struct Watches(Vec<FieldTarget>);
+
+fn watch(mut commands: Commands) {
+    prompt_field_target(&mut commands, "watch: ", add_watch);
+}
+
+fn add_watch(
+    In(target): In<FieldTarget>,
+    mut watches: ResMut<Watches>,
+    mut minibuffer: Minibuffer,
+) {
+    minibuffer.message(format!("Watching {}.", target.label));
+    watches.0.push(target);
+}
+
+fn unwatch(watches: Res<Watches>, mut minibuffer: Minibuffer) {
+    if watches.0.is_empty() {
+        minibuffer.message("Nothing is watched.");
+        return;
+    }
+    let labels = Trie::from_iter(
+        watches
+            .0
+            .iter()
+            .enumerate()
+            .map(|(index, target)| (target.label.clone(), index)),
+    );
+    minibuffer.prompt_map("unwatch: ", labels).observe(
+        |mut trigger: On<Completed<usize>>,
+         mut watches: ResMut<Watches>,
+         mut minibuffer: Minibuffer| {
+            match trigger.event_mut().state.take_result().unwrap() {
+                Ok(index) => {
+                    let target = watches.0.remove(index);
+                    minibuffer.message(format!("Stopped watching {}.", target.label));
+                }
+                Err(e) => {
+                    minibuffer.message(format!("{e}"));
+                }
+            }
+        },
+    );
+}
+
+fn watch_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let watches = world.resource::<Watches>();
+
+    egui::Area::new(egui::Id::new("watches"))
+        .anchor(egui::Align2::RIGHT_TOP, (-8., 8.))
+        .show(egui_context.get_mut(), |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                for target in &watches.0 {
+                    let value = match target.read(world) {
+                        Ok(value) => format!("{value:?}"),
+                        Err(e) => format!("<{e}>"),
+                    };
+                    ui.monospace(format!("{} = {value}", target.label));
+                }
+            });
+        });
+}
@@ -1,9 +1,15 @@
+use crate::{read_only::readonly_entity_ui, ReadOnlyInspectors};
 use bevy_app::{App, Plugin};
 use bevy_ecs::{
-    prelude::{Res, ResMut},
+    entity::Entity,
+    prelude::{not, resource_exists, IntoScheduleConfigs, Res, ResMut, With, World},
     schedule::SystemCondition,
 };
-use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use bevy_inspector_egui::{
+    bevy_egui::{EguiContext, EguiPrimaryContextPass, PrimaryEguiContext},
+    bevy_inspector, egui,
+    quick::WorldInspectorPlugin,
+};
 use bevy_minibuffer::{prelude::*, prompt::PromptState};
 use bevy_reflect::Reflect;
 use bevy_state::app::AppExtStates;
@@ -22,7 +28,9 @@ enum WorldInspectorState {
 
 /// ## Adds the 'inspect_world' act
 ///
-/// This act toggles the visibility of the world inspector.
+/// This act toggles the visibility of the world inspector. While
+/// [ReadOnlyInspectors] is present, it lists the entities and their components
+/// without allowing edits instead.
 ///
 /// ## Usage
 ///
@@ -77,10 +85,48 @@ impl Default for WorldActs {
 impl Plugin for WorldActs {
     fn build(&self, app: &mut App) {
         app.add_plugins(
-            WorldInspectorPlugin::default()
-                .run_if(in_state(PromptState::Visible).and(in_state(WorldInspectorState::Visible))),
+            WorldInspectorPlugin::default().run_if(
+                in_state(PromptState::Visible)
+                    .and(in_state(WorldInspectorState::Visible))
+                    .and(not(resource_exists::<ReadOnlyInspectors>)),
+            ),
+        )
+        .add_systems(
+            EguiPrimaryContextPass,
+            readonly_world_ui.run_if(
+                in_state(PromptState::Visible)
+                    .and(in_state(WorldInspectorState::Visible))
+                    .and(resource_exists::<ReadOnlyInspectors>),
+            ),
         )
         .init_state::<WorldInspectorState>();
         self.warn_on_unused_acts();
     }
 }
+
+/// Show the entities and their components without allowing edits.
+fn readonly_world_ui(world: &mut World) {
+    let egui_context = world
+        .query_filtered::<&mut EguiContext, With<PrimaryEguiContext>>()
+        .single(world);
+
+    let Ok(egui_context) = egui_context else {
+        return;
+    };
+    let mut egui_context = egui_context.clone();
+    let mut entities: Vec<Entity> = world.query::<Entity>().iter(world).collect();
+    entities.sort();
+
+    egui::Window::new("World Inspector")
+        .default_size((320., 160.))
+        .show(egui_context.get_mut(), |ui| {
+            egui::ScrollArea::both().show(ui, |ui| {
+                for entity in entities {
+                    egui::CollapsingHeader::new(bevy_inspector::guess_entity_name(world, entity))
+                        .id_salt(entity)
+                        .show(ui, |ui| readonly_entity_ui(world, entity, ui));
+                }
+                ui.allocate_space(ui.available_size());
+            });
+        });
+}